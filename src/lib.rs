@@ -2,7 +2,9 @@
 ©Maldiran
 This library provides a Triangle struct that has various properties of a given triangle.
 
-The struct's data consists of two types: Option<f64> and [Option<f64>; 3].
+The struct's data is returned as two types: Option<f64> and [Option<f64>; 3]. Apart from the sides, every property is stored in a lazily initialised cell, so it is calculated on the first access and cached for the next ones.
+
+The crate needs Rust 1.86 or newer, the interval type uses f64::next_up() and f64::next_down().
 
 The Option<f64> type is used for:
 
@@ -10,6 +12,7 @@ The Option<f64> type is used for:
     Area
     Inradius
     Circumradius
+    Brocard angle
 
 The [Option<f64>; 3] data are ordered in a specific way:
 
@@ -17,14 +20,57 @@ The [Option<f64>; 3] data are ordered in a specific way:
     Heights: The height's index reflects the side's index, so that sides[i] * heights[i] / 2.0 = area.
     Angles: The angle at index i is opposite to sides[i]. Their values are given in radians. To convert them to degrees, use standard library methods such as f64.to_degrees() and f64.to_radians().
     Medians: This starts from the middle of the side of a given index.
+    Bisectors: The angle bisector that starts at the vertex opposite to the side of a given index and ends on that side.
     Sines, cosines, tangents: Their index corresponds to the angle.
 
-A new struct is created using the method new(sides: [Option<f64>; 3], angles: [Option<f64>; 3]). It calculates all properties at creation time. Some fields of the above function can be left as None. If the triangle can be constructed from the given data, the function will return Some(Triangle), if not you will get None. Angles specified in the above function are used only to calculate sides, they are discarded later, so there can be a situation when someone inputs angle 0.5000 and gets 0.4999 from the struct. Sides always take precedence over the angles, so if you provide incorrect angle, but the side data will be sufficient, it will not be taken into consideration and the function will return Some(Triangle).
+Triangle uses f64 and is a shorthand for GenericTriangle<f64>. The struct, its constructors and accessors are generic over the Float trait, which is implemented for f64 and f32, e.g. GenericTriangle::new_from_sides([3.0_f32, 4.0, 5.0]). You can implement Float for your own number type (fixed-point, higher precision, ...): it needs the arithmetic operators, comparison, conversion from and to f64, pi, the rounding error used in the checks, square root and the trigonometric functions. Everything described below as f64 is T in GenericTriangle<T>.
+
+The area and the angles are calculated with Kahan's formulas, which sort the sides before subtracting them. Unlike plain Heron's formula and acos of the cosine, they keep their precision for needle-like triangles, so such triangles do not get zero or NaN areas and angles.
+
+Measured sides can carry their standard deviations. Uncertain is a Float with a value and contributions: [f64; 3], the derivative of the value by each side times that side's deviation, which are propagated through every calculation. MeasuredTriangle is GenericTriangle<Uncertain> and is created with MeasuredTriangle::new_measured(sides: [f64; 3], deviations: [f64; 3]), or with new_from_sides(Uncertain::sides(sides, deviations)) and the other constructors. Every property then comes with its first-order standard deviation, Uncertain.deviation(), e.g. triangle.area().unwrap().deviation(). Comparisons and the checks use only the values.
+
+For guaranteed bounds, use Interval as the number type. It is a closed interval created with Interval::new(lo: f64, hi: f64), Interval::point(value: f64) or Interval::around(value: f64, radius: f64), with lo(), hi(), width() and contains(value: f64). Every operation rounds the lower bound down and the upper bound up, so the properties of IntervalTriangle (GenericTriangle<Interval>), e.g. area(), angle(i) or circumradius(), are intervals that contain the true value. An interval is smaller than another one only when it is completely below it, so overlapping intervals cannot be compared. Whether interval sides are a triangle is told by IntervalTriangle::validity(sides: [Interval; 3]) → Validity: Valid, Invalid or Undecided, when the intervals contain both. The constructors accept the sides when they are Valid and return an error when they are Undecided.
+
+Many quantities are rational in the squared sides, so for rational sides they can be calculated exactly. ExactTriangle is created with ExactTriangle::new_from_integers(sides: [u64; 3]) or new_from_sides(sides: [Rational; 3]) (try_new_from_sides returns Result<ExactTriangle, TriangleError<Rational>>). Rational is a fraction of two BigInts in lowest terms, both implemented in the crate with the arithmetic operators, comparison and Display. ExactTriangle gives area_squared_16() (an integer for integer sides), area_squared(), area() (None if it is not rational), cosine(i), sine_squared(i), median_squared(i), height_squared(i) with their array forms, circumradius_squared(), inradius_squared(), radius_ratio() (R / r) and radius_product() (R * r). side_class(), angle_class(), is_right(), is_degenerate() and is_heronian() (integer sides and integer area) need no tolerance. to_triangle() rounds the sides to f64.
+
+Integer triangles can be enumerated with iterators that yield the sides as [u64; 3], from the shortest one, so every triangle is given once and the sides are ready for new_from_sides or ExactTriangle::new_from_integers: PerimeterTriangles::new(perimeter: u64) gives all triangles with the perimeter, HeronianTriangles::new(max_side: u64) all triangles with integer area and sides up to the bound, and PythagoreanTriples::new(max_hypotenuse: u64) the primitive Pythagorean triples from Euclid's formula. The SidesIterator trait adds filters to every iterator of sides: primitive() (sides without a common divisor), with_side_class(class: SideClass) and with_angle_class(class: AngleClass), which are exact, and triangles(), which yields Triangles instead, e.g. PerimeterTriangles::new(24).with_angle_class(AngleClass::Right).triangles().
+
+A new struct is created using the method new(sides: [Option<f64>; 3], angles: [Option<f64>; 3]). It calculates all properties at creation time. Some fields of the above function can be left as None. If the triangle can be constructed from the given data, the function will return Some(Triangle), if not you will get None. Angles specified in the above function are used only to calculate sides, they are discarded later, so there can be a situation when someone inputs angle 0.5000 and gets 0.4999 from the struct. Sides always take precedence over the angles, so if you provide incorrect angle, but the side data will be sufficient, it will not be taken into consideration and the function will return Some(Triangle). When the angles are needed to calculate sides, they have to be between 0 and pi and sum to pi, otherwise the function will return None.
+
+If you want the angles to be checked against the sides, use try_new_checked(sides: [Option<f64>; 3], angles: [Option<f64>; 3], tolerance: f64). It rejects three angles that do not sum to pi within the tolerance, and compares every given angle with the angle of the constructed triangle. Angles that differ by more than the tolerance are reported per index as TriangleError::InconsistentAngles.
+
+If two sides and an angle that is not between them are given, there can be zero, one or two triangles matching the data. new() returns the triangle only when it is the only solution. To get all of them, use new_ssa(sides: [Option<f64>; 3], angles: [Option<f64>; 3]) → Vec<Triangle>, which takes exactly two sides and one angle and returns every matching triangle. When the angle is right in front of the adjacent side (the tangent case), only one triangle is returned.
 
 Another way to create the struct is through the new_from_sides(sides: [f64; 3]) method, which takes just the lengths of the sides. This is slightly faster than the previous method.
 
 You can also create a blank struct with the function new_blank(sides: [f64; 3]). This will create a triangle struct with no pre-calculated properties, and all of them will be calculated on user demand.
 
+If you know other properties than the sides and the angles, use solve(measurements: &Measurements<f64>, tolerance: f64) → Vec<Triangle> or try_solve → Result<Vec<Triangle>, TriangleError>. Measurements has [Option<f64>; 3] fields sides, angles, heights, medians and bisectors, and Option<f64> fields area, perimeter, inradius and circumradius, which are None by default, e.g. Measurements { sides: [None, Some(4.0), Some(3.0)], heights: [Some(2.4), None, None], ..Default::default() }. Any three independent measurements are enough. The solver searches numerically from many starting triangles and returns every triangle that matches all the measurements within the relative tolerance, so there can be more than one, e.g. here the angle between the given sides can be acute or obtuse. The measurements are listed as pairs of Quantity (Side(i), Angle(i), Height(i), Median(i), Bisector(i), Area, Perimeter, Inradius, Circumradius) and value by Measurements.quantities().
+
+If you have points instead of lengths, use new_from_points(points: [(f64, f64); 3]) or new_from_points_3d(points: [(f64, f64, f64); 3]). Side i is the distance between the points (i + 1) % 3 and (i + 2) % 3, so it is opposite to point i. The points are kept as the triangle's placement. Points on one line, including points in the same place, are rejected with TriangleError::Collinear.
+
+A triangle can also be created from its three medians with new_from_medians(medians: [f64; 3]), its three heights with new_from_heights(heights: [f64; 3]) or its three bisectors with new_from_bisectors(bisectors: [f64; 3]), where the value at index i belongs to the side at index i. Medians and heights use closed formulas: the medians make a triangle whose medians are 3/4 of the sides, and the sides are proportional to the reciprocals of the heights. Bisectors are solved numerically by solve.
+
+Every constructor has a try_ twin (try_new, try_new_ssa, try_new_from_sides, try_new_blank, try_new_from_points, try_new_from_points_3d, try_new_from_medians, try_new_from_heights, try_new_from_bisectors) that returns Result<Triangle, TriangleError> instead of Option<Triangle> (try_new_ssa returns Result<Vec<Triangle>, TriangleError>). The TriangleError enum tells why the triangle could not be created and carries the offending values:
+    NonPositiveSide { index, side }: A side is zero or negative.
+    TriangleInequality { sides }: The longest side is longer than the other two together.
+    InvalidAngle { index, angle }: An angle is not between 0 and pi.
+    AngleSum { angles, sum }: The angles do not sum to pi.
+    InsufficientData { sides, angles }: Not enough sides and angles were given, with the given ones.
+    NoSolution { sides, angles }: Two sides and an angle that no triangle can have.
+    Ambiguous { solutions }: Two sides and an angle that two triangles have, their sides are given.
+    InvalidIndex { index }: An index other than 0, 1 or 2.
+    Collinear { points }: Points that do not make a triangle, as Placement::Plane or Placement::Space.
+    Degenerate { sides }: A derived triangle (see below) that would be degenerate, with its sides.
+    InvalidMeasurement { quantity, value }: A measurement given to the solver (see below) that is not positive, or an angle that is not between 0 and pi.
+    Underdetermined { independent }: Fewer than 3 independent measurements.
+    Inconsistent { quantity, residual }: More measurements than needed, and no triangle matches all of them. The quantity that fits the worst in the closest triangle is given, with its relative difference.
+    Unsolvable { quantity, residual }: Exactly as many measurements as needed, but no triangle has them.
+    MedianInequality { medians }: The medians do not make a triangle, so no triangle has them.
+    HeightInequality { heights }: The reciprocals of the heights do not make a triangle, so no triangle has them.
+    InconsistentAngles { mismatches }: Given angles that do not match the sides, as (given, derived) pairs at their index.
+The same checks are available as validate_sides(sides: [f64; 3]) and validate_angles(angles: [f64; 3]), which return Result<(), TriangleError>.
+
 To access the struct's data, use:
 Triangle.side(i: usize) → Option<f64>: The side at index i.
 Triangle.sides() → [Option<f64>; 3]: An array of sides. Triangle.perimeter() → Option<f64>
@@ -33,8 +79,26 @@ Triangle.height(i: usize) → Option<f64>
 Triangle.heights() → [Option<f64>; 3]
 Triangle.median(i: usize) → Option<f64>
 Triangle.medians() → [Option<f64>; 3]
+Triangle.bisector(i: usize) → Option<f64>: The angle bisector that ends on the side at index i.
+Triangle.bisectors() → [Option<f64>; 3]
+Triangle.bisector_ratio(i: usize) → Option<f64>: The ratio in which the bisector divides the side at index i, the part next to the vertex (i + 1) % 3 to the part next to the vertex (i + 2) % 3.
+Triangle.bisector_ratios() → [Option<f64>; 3]
+Triangle.bisector_foot(i: usize) → Option<(f64, f64)>: Distances of the bisector's foot from the vertices (i + 1) % 3 and (i + 2) % 3.
+Triangle.bisector_feet() → [Option<(f64, f64)>; 3]
 Triangle.inradius() → Option<f64>
 Triangle.circumradius() → Option<f64>
+Triangle.exradius(i: usize) → Option<f64>: The radius of the excircle tangent to the side at index i, None for degenerate triangles.
+Triangle.exradii() → [Option<f64>; 3]
+Triangle.check_exradii(tolerance: f64) → bool: Checks that the sum of exradii is 4 * circumradius + inradius and the sum of their reciprocals is 1 / inradius, within the relative tolerance.
+Triangle.tangent_length(i: usize) → Option<f64>: The length of both tangents from the vertex i to the incircle, the semi-perimeter minus sides[i].
+Triangle.tangent_lengths() → [Option<f64>; 3]
+Triangle.incircle_split(i: usize) → Option<(f64, f64)>: Distances of the point where the incircle touches the side at index i from the vertices (i + 1) % 3 and (i + 2) % 3.
+Triangle.incircle_splits() → [Option<(f64, f64)>; 3]
+Triangle.excircle_split(i: usize) → Option<(f64, f64)>: The same for the excircle tangent to the side at index i, it is the incircle split swapped.
+Triangle.excircle_splits() → [Option<(f64, f64)>; 3]
+Triangle.excircle_tangent_length() → Option<f64>: The length of both tangents from a vertex to the excircle tangent to the opposite side. It is the semi-perimeter, the same for every vertex, so it takes no index.
+Triangle.contact_side(i: usize) → Option<f64>: Side i of the contact triangle, between the points where the incircle touches the sides next to the vertex i.
+Triangle.contact_sides() → [Option<f64>; 3]
 Triangle.angle(i: usize) → Option<f64>
 Triangle.angles() → [Option<f64>; 3]
 Triangle.sine(i: usize) → Option<f64>
@@ -43,56 +107,382 @@ Triangle.cosine(i: usize) → Option<f64>
 Triangle.cosines() → [Option<f64>; 3]
 Triangle.tangent(i: usize) → Option<f64>
 Triangle.tangents() → [Option<f64>; 3]
+Triangle.brocard_angle() → Option<f64>: The angle ω with cot ω = cot A + cot B + cot C, calculated from the tangents.
+
+Triangles can be classified by sides and by angles. The tolerance is relative to the longest side, so a triangle created from angles, whose sides carry rounding errors, is still recognized:
+Triangle.classify(tolerance: f64) → Classification: A struct with sides: SideClass (Equilateral, Isosceles, Scalene) and angles: AngleClass (Acute, Right, Obtuse, Degenerate).
+Triangle.side_class(tolerance: f64) → SideClass
+Triangle.angle_class(tolerance: f64) → AngleClass
+Triangle.is_equilateral(tolerance: f64), is_isosceles, is_scalene, is_acute, is_right, is_obtuse, is_degenerate → bool: Equilateral triangles are isosceles too.
+
+Although the triangle is made only of sides, it can be placed in the plane. Vertex i is opposite to sides[i]:
+Triangle.vertices() → [(f64, f64); 3]: Vertex 0 at the origin, side 2 along the x axis, so vertex 1 is at (sides[2], 0.0), and vertex 2 above it (counterclockwise order).
+Triangle.vertices_with(anchor: (f64, f64), rotation: f64, orientation: Orientation) → [(f64, f64); 3]: Vertex 0 at the anchor and side 2 rotated counterclockwise by the angle in radians. Orientation::Clockwise mirrors vertex 2 to the other side of side 2.
+Triangle.placement() → Option<Placement>: The points of the vertices, Placement::Plane([(f64, f64); 3]) or Placement::Space([(f64, f64, f64); 3]), if the triangle has them. They are given by new_from_points, new_from_points_3d or place, moved by scale (vertex 0 stays in place) and dropped by set_side and set_sides.
+Triangle.place(anchor: (f64, f64), rotation: f64, orientation: Orientation): Stores the result of vertices_with as the placement.
+
+The classical centers are given by the Center enum: Centroid, Incenter, Circumcenter and Orthocenter. Their barycentric coordinates need only the sides, the points need the placement:
+Triangle.barycentric(center: Center) → [f64; 3]: Normalized barycentric coordinates, the weight at index i belongs to vertex i.
+Triangle.center_point(center: Center) → Option<Point>: Point::Plane(x, y) or Point::Space(x, y, z), None if the triangle has no placement.
+Triangle.center_distance(from: Center, to: Center) → f64: e.g. the distance between the circumcenter and the incenter is sqrt(R * (R - 2 * r)).
+Triangle.barycentric_distance(p: [f64; 3], q: [f64; 3]) → f64: Distance between any two points given in barycentric coordinates.
+
+Other centers are kept in a CenterRegistry, like in Kimberling's Encyclopedia of Triangle Centers. A center is a CenterFunction, Barycentric(f) or Trilinear(f), where f(a, b, c) gives the first coordinate from the sides and the other two are f(b, c, a) and f(c, a, b). CenterRegistry::new() has the incenter X(1), centroid X(2), circumcenter X(3), orthocenter X(4), nine-point center X(5), symmedian point X(6), Gergonne point X(7), Nagel point X(8), Spieker center X(10), Fermat point X(13) and de Longchamps point X(20), CenterRegistry::empty() has none:
+CenterRegistry.register(name: &str, kimberling: Option<u32>, function: CenterFunction) → Option<NamedCenter>: Adds a center, or replaces the one with the same name and returns it.
+CenterRegistry.get(name: &str), CenterRegistry.kimberling(number: u32) → Option<&NamedCenter>: A struct with name, kimberling and function. Names are not case sensitive.
+CenterRegistry.evaluate(name: &str, triangle: &Triangle) → Option<[f64; 3]>
+Triangle.center_of(function: &CenterFunction) → Option<[f64; 3]>: Normalized barycentric coordinates, None if the coordinates sum to zero.
+Triangle.center_point_of(function: &CenterFunction) → Option<Point>
+
+Points can be converted between barycentric, trilinear and Cartesian coordinates. Barycentric coordinates do not have to be normalized, trilinear ones are returned as the signed distances to the sides (negative outside of the triangle). Conversions from and to Cartesian coordinates need the placement, points in the space are projected to the plane of the triangle:
+Triangle.normalized_barycentric(barycentric: [f64; 3]) → Option<[f64; 3]>: None if the coordinates sum to zero.
+Triangle.barycentric_from_trilinear(trilinear: [f64; 3]), trilinear_from_barycentric(barycentric: [f64; 3]) → Option<[f64; 3]>
+Triangle.barycentric_from_cartesian(point: Point) → Option<[f64; 3]>
+Triangle.cartesian_from_barycentric(barycentric: [f64; 3]) → Option<Point>
+Triangle.side_distances(point: Point) → Option<[f64; 3]>: Signed distances of the point to the sides.
+Triangle.locate(barycentric: [f64; 3], tolerance: f64), locate_point(point: Point, tolerance: f64) → Option<Location>: Inside, OnEdge(i), AtVertex(i) or Outside. A point closer to a side than the tolerance relative to the longest side is on that side.
 
-Every one of them apart from side and sides can mutate the struct apart from only displaying a value:
-Triangle.area_mut() → Option<f64>: Mutates the area.
-This is useful only when creating with new_blank function, since new and new_from_sides generate all the data ahead of time so there is no reason to mutate them later. You can access data created with new_blank without mutating the struct, but the data will be generated with every access.
+Derived triangles are calculated from the sides and the angles and returned as new triangles. Vertex i of a derived triangle belongs to the side or the vertex i of the original one. They return Result<Triangle, TriangleError>, with TriangleError::Degenerate when the result is degenerate:
+Triangle.medial(): Vertices in the middle of the sides.
+Triangle.anticomplementary(): The triangle this one is medial to.
+Triangle.orthic(): Vertices at the feet of the heights, degenerate for right triangles.
+Triangle.intouch(): Vertices where the incircle touches the sides.
+Triangle.excentral(): Vertices at the centers of the excircles.
+Triangle.tangential(): Sides tangent to the circumcircle at the vertices, undefined for right triangles.
+Triangle.pedal(barycentric: [f64; 3]): Vertices at the feet of the perpendiculars from the point to the sides, degenerate for points on the circumcircle.
+
+The nine-point circle and the Euler line:
+Triangle.nine_point_radius() → Option<f64>: Half of the circumradius.
+Triangle.nine_point_center() → [f64; 3], nine_point_center_point() → Option<Point>: Halfway between the circumcenter and the orthocenter.
+Triangle.euler_line() → Option<EulerLine>: A struct with the barycentric coordinates of the circumcenter and the orthocenter and the length between them. EulerLine.direction() is the barycentric vector between them and EulerLine.point(point: EulerPoint) gives Circumcenter, Centroid, NinePointCenter, Orthocenter or DeLongchamps, which are at EulerPoint.ratio() 0, 1/3, 1/2, 1 and -1 of the way. The line is None for equilateral triangles, where all the centers are in the same place.
+Triangle.euler_direction() → Option<Point>: Unit vector from the circumcenter to the orthocenter, needs the placement.
+Triangle.feuerbach_point() → Option<[f64; 3]>, feuerbach_point_cartesian() → Option<Point>: Where the incircle touches the nine-point circle, None for equilateral triangles, where the circles are the same.
+Triangle.check_euler(tolerance: f64) → bool: Checks OH² = 9R² - (a² + b² + c²), OG = OH / 3, ON = OH / 2 and IN = R / 2 - r, the tolerance is relative to the circumradius.
+
+Brocard points, the lines from the first one to vertices 0, 1 and 2 make the Brocard angle with the sides towards vertices 1, 2 and 0, and from the second one with the sides towards vertices 2, 0 and 1:
+Triangle.brocard_points() → [[f64; 3]; 2]: Normalized barycentric coordinates of the first and the second point.
+Triangle.brocard_points_cartesian() → Option<[Point; 2]>
+Triangle.brocard_circle() → ([f64; 3], f64): Barycentric coordinates of the center and the radius of the circle with the diameter from the circumcenter to the symmedian point, which goes through both Brocard points.
+
+Every one of them apart from side and sides has a _mut twin, e.g. Triangle.area_mut() → Option<f64>. Since the accessors cache the values through &self, the twins return the same values and are kept only for compatibility.
+Triangle created with new_blank calculates its properties on the first access, while new and new_from_sides calculate all of them ahead of time. The cache uses std::cell::OnceCell, so Triangle is not Sync. If you want to share a triangle between threads, e.g. behind Arc, convert it to SyncTriangle with SyncTriangle::from(triangle). It has the same methods, but caches the values in std::sync::OnceLock. Both of them are GenericTriangle<T, S: Slot<T>>, where Slot is the trait of the cell used for the cache.
+
+The sides can be changed later:
+Triangle.set_side(i: usize, length: f64) → Result<(), TriangleError>: Changes the side at index i.
+Triangle.set_sides(sides: [f64; 3]) → Result<(), TriangleError>: Changes all sides.
+Triangle.scale(k: f64) → Result<(), TriangleError>: Multiplies all sides by k.
+If the new sides do not make a triangle, the struct is left unchanged and the error is returned. Changing a side clears the cache, since every property depends on all sides. Scaling keeps the cached angles, sines, cosines, tangents and the Brocard angle, multiplies the cached lengths by k and the area by k squared.
 */
 pub mod triangle_sides {
 
-    #[derive(Copy, Clone, Debug)]
-    pub struct Triangle {
-        sides: [Option<f64>; 3],
-        heights: [Option<f64>; 3],
-        medians: [Option<f64>; 3],
-        angles: [Option<f64>; 3],
-        sines: [Option<f64>; 3],
-        cosines: [Option<f64>; 3],
-        tangents: [Option<f64>; 3],
-        perimeter: Option<f64>,
-        area: Option<f64>,
-        inradius: Option<f64>,
-        circumradius: Option<f64>,
+    mod brocard;
+    mod centers;
+    mod cevians;
+    mod classification;
+    mod coordinates;
+    mod derived;
+    mod enumerate;
+    mod euler;
+    mod exact;
+    mod float;
+    mod interval;
+    mod placement;
+    mod rational;
+    mod registry;
+    mod solver;
+    mod tangency;
+    #[cfg(test)]
+    pub(crate) mod testing;
+    mod uncertain;
+
+    pub use centers::Center;
+    pub use classification::{AngleClass, Classification, SideClass};
+    pub use coordinates::Location;
+    pub use enumerate::{HeronianTriangles, PerimeterTriangles, PythagoreanTriples, SidesIterator};
+    pub use euler::{EulerLine, EulerPoint};
+    pub use exact::ExactTriangle;
+    pub use float::Float;
+    pub use interval::{Interval, IntervalTriangle, Validity};
+    pub use placement::{Orientation, Placement, Point};
+    pub use rational::{BigInt, Rational};
+    pub use registry::{CenterFn, CenterFunction, CenterRegistry, NamedCenter};
+    pub use solver::{Measurements, Quantity};
+    use std::cell::OnceCell;
+    use std::sync::OnceLock;
+    pub use uncertain::{MeasuredTriangle, Uncertain};
+
+    //lazily initialised storage of a single calculated property
+    pub trait Slot<T>: Default + Clone {
+        fn get(&self) -> Option<T>;
+        fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> T;
+        fn take(&mut self) -> Option<T>;
+    }
+
+    impl<T: Copy> Slot<T> for OnceCell<T> {
+        fn get(&self) -> Option<T> {
+            OnceCell::get(self).copied()
+        }
+        fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> T {
+            *OnceCell::get_or_init(self, f)
+        }
+        fn take(&mut self) -> Option<T> {
+            OnceCell::take(self)
+        }
+    }
+
+    impl<T: Copy> Slot<T> for OnceLock<T> {
+        fn get(&self) -> Option<T> {
+            OnceLock::get(self).copied()
+        }
+        fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> T {
+            *OnceLock::get_or_init(self, f)
+        }
+        fn take(&mut self) -> Option<T> {
+            OnceLock::take(self)
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenericTriangle<T = f64, S = OnceCell<T>> {
+        sides: [T; 3],
+        heights: [S; 3],
+        medians: [S; 3],
+        bisectors: [S; 3],
+        exradii: [S; 3],
+        angles: [S; 3],
+        sines: [S; 3],
+        cosines: [S; 3],
+        tangents: [S; 3],
+        perimeter: S,
+        area: S,
+        inradius: S,
+        circumradius: S,
+        brocard_angle: S,
+        placement: Option<Placement<T>>,
     }
 
-    impl Triangle {
-        pub fn new(sides: [Option<f64>; 3], mut angles: [Option<f64>; 3]) -> Option<Triangle> {
+    pub type Triangle<S = OnceCell<f64>> = GenericTriangle<f64, S>;
+
+    //same triangle, but it can be shared between threads, e.g. behind Arc
+    pub type SyncTriangle<T = f64> = GenericTriangle<T, OnceLock<T>>;
+
+    impl<T: Float> From<GenericTriangle<T>> for SyncTriangle<T> {
+        fn from(triangle: GenericTriangle<T>) -> SyncTriangle<T> {
+            triangle.convert()
+        }
+    }
+
+    impl<T: Float> From<SyncTriangle<T>> for GenericTriangle<T> {
+        fn from(triangle: SyncTriangle<T>) -> GenericTriangle<T> {
+            triangle.convert()
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum TriangleError<T = f64> {
+        NonPositiveSide {
+            index: usize,
+            side: T,
+        },
+        TriangleInequality {
+            sides: [T; 3],
+        },
+        InvalidAngle {
+            index: usize,
+            angle: T,
+        },
+        AngleSum {
+            angles: [Option<T>; 3],
+            sum: T,
+        },
+        InsufficientData {
+            sides: [Option<T>; 3],
+            angles: [Option<T>; 3],
+        },
+        NoSolution {
+            sides: [Option<T>; 3],
+            angles: [Option<T>; 3],
+        },
+        Ambiguous {
+            solutions: [[T; 3]; 2],
+        },
+        InconsistentAngles {
+            mismatches: [Option<(T, T)>; 3],
+        },
+        InvalidIndex {
+            index: usize,
+        },
+        Collinear {
+            points: Placement<T>,
+        },
+        Degenerate {
+            sides: [T; 3],
+        },
+        InvalidMeasurement {
+            quantity: Quantity,
+            value: T,
+        },
+        Underdetermined {
+            independent: u8,
+        },
+        Inconsistent {
+            quantity: Quantity,
+            residual: T,
+        },
+        Unsolvable {
+            quantity: Quantity,
+            residual: T,
+        },
+        MedianInequality {
+            medians: [T; 3],
+        },
+        HeightInequality {
+            heights: [T; 3],
+        },
+    }
+
+    impl<T: std::fmt::Display + std::fmt::Debug> std::fmt::Display for TriangleError<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                TriangleError::NonPositiveSide { index, side } => {
+                    write!(f, "side {} has non-positive length {}", index, side)
+                }
+                TriangleError::TriangleInequality { sides } => write!(
+                    f,
+                    "sides {}, {}, {} do not satisfy the triangle inequality",
+                    sides[0], sides[1], sides[2]
+                ),
+                TriangleError::InvalidAngle { index, angle } => {
+                    write!(f, "angle {} is not between 0 and pi: {}", index, angle)
+                }
+                TriangleError::AngleSum { angles, sum } => {
+                    write!(f, "angles {} sum to {} instead of pi", given(angles), sum)
+                }
+                TriangleError::InsufficientData { sides, angles } => write!(
+                    f,
+                    "sides {} and angles {} are not enough to construct a triangle",
+                    given(sides),
+                    given(angles)
+                ),
+                TriangleError::NoSolution { sides, angles } => write!(
+                    f,
+                    "no triangle has sides {} and angles {}",
+                    given(sides),
+                    given(angles)
+                ),
+                TriangleError::Ambiguous { solutions } => write!(
+                    f,
+                    "two triangles match the data, with sides {:?} and {:?}",
+                    solutions[0], solutions[1]
+                ),
+                TriangleError::InconsistentAngles { mismatches } => {
+                    write!(f, "angles do not match the sides:")?;
+                    for (index, mismatch) in mismatches.iter().enumerate() {
+                        if let Some((given, derived)) = mismatch {
+                            write!(f, " angle {} given {}, derived {};", index, given, derived)?;
+                        }
+                    }
+                    Ok(())
+                }
+                TriangleError::InvalidIndex { index } => {
+                    write!(f, "index {} is not 0, 1 or 2", index)
+                }
+                TriangleError::Collinear { points } => {
+                    write!(f, "points {:?} are collinear", points)
+                }
+                TriangleError::Degenerate { sides } => write!(
+                    f,
+                    "sides {}, {}, {} make a degenerate triangle",
+                    sides[0], sides[1], sides[2]
+                ),
+                TriangleError::InvalidMeasurement { quantity, value } => {
+                    write!(f, "{:?} cannot be {}", quantity, value)
+                }
+                TriangleError::Underdetermined { independent } => write!(
+                    f,
+                    "only {} of the measurements are independent, 3 are needed",
+                    independent
+                ),
+                TriangleError::Inconsistent { quantity, residual } => write!(
+                    f,
+                    "measurements are inconsistent, {:?} is off by {} of its value",
+                    quantity, residual
+                ),
+                TriangleError::Unsolvable { quantity, residual } => write!(
+                    f,
+                    "no triangle matches the measurements, {:?} is off by {} of its value",
+                    quantity, residual
+                ),
+                TriangleError::MedianInequality { medians } => write!(
+                    f,
+                    "medians {}, {}, {} do not satisfy the triangle inequality",
+                    medians[0], medians[1], medians[2]
+                ),
+                TriangleError::HeightInequality { heights } => write!(
+                    f,
+                    "reciprocals of heights {}, {}, {} do not satisfy the triangle inequality",
+                    heights[0], heights[1], heights[2]
+                ),
+            }
+        }
+    }
+
+    impl<T: std::fmt::Display + std::fmt::Debug> std::error::Error for TriangleError<T> {}
+
+    //values that were given, in the order of their indices, e.g. "1, unknown, 2"
+    fn given<T: std::fmt::Display>(values: &[Option<T>; 3]) -> String {
+        values
+            .iter()
+            .map(|value| match value {
+                Some(value) => value.to_string(),
+                None => String::from("unknown"),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    impl<T: Float> GenericTriangle<T> {
+        pub fn new(sides: [Option<T>; 3], angles: [Option<T>; 3]) -> Option<GenericTriangle<T>> {
+            Self::try_new(sides, angles).ok()
+        }
+        pub fn try_new(
+            sides: [Option<T>; 3],
+            mut angles: [Option<T>; 3],
+        ) -> Result<GenericTriangle<T>, TriangleError<T>> {
             let mut side_num: u8 = 0;
             let mut angle_num: u8 = 0;
             for side in sides {
-                match side {
-                    Some(_) => side_num += 1,
-                    None => (),
-                };
+                if side.is_some() {
+                    side_num += 1;
+                }
             }
             for angle in angles {
-                match angle {
-                    Some(_) => angle_num += 1,
-                    None => (),
-                };
+                if angle.is_some() {
+                    angle_num += 1;
+                }
             }
+            let given_angle_num = angle_num;
+            let given_angles = angles;
             if angle_num == 2 {
                 angles = Self::special_third_angle(angles);
                 angle_num = 3;
             }
-            if angle_num == 3 {
-                Self::check_triangle_angles([
-                    angles[0].unwrap(),
-                    angles[1].unwrap(),
-                    angles[2].unwrap(),
-                ]);
+            if angle_num == 3 && side_num < 3 {
+                //angles are used to calculate sides only when sides are missing
+                let angles_values = [angles[0].unwrap(), angles[1].unwrap(), angles[2].unwrap()];
+                let given_sum = given_angles
+                    .iter()
+                    .flatten()
+                    .fold(T::zero(), |sum, angle| sum + *angle);
+                if given_angle_num == 2 && given_sum >= T::pi() {
+                    return Err(TriangleError::AngleSum {
+                        angles: given_angles,
+                        sum: given_sum,
+                    });
+                }
+                Self::validate_angles(angles_values)?;
             }
-            let sides_complete: [f64; 3] = match (side_num, angle_num) {
+            let sides_complete: [T; 3] = match (side_num, angle_num) {
                 (3, _) => {
                     //all 3 sides known
                     [sides[0].unwrap(), sides[1].unwrap(), sides[2].unwrap()]
@@ -109,10 +499,18 @@ pub mod triangle_sides {
                             [s1, Self::third_side_calc((s1, s2), a), s2]
                         }
                         _ => {
-                            return None;
-                        } //angle other than oposite of the missing side might
-                          //provide two solutions, so the function will not
-                          //deciide which one of them is desired
+                            //angle other than oposite of the missing side might
+                            //provide two solutions, new_ssa() returns all of them
+                            //and here only the unique one is accepted
+                            let mut solutions = Self::try_new_ssa(sides, angles)?;
+                            return match solutions.len() {
+                                1 => Ok(solutions.pop().unwrap()),
+                                2 => Err(TriangleError::Ambiguous {
+                                    solutions: [solutions[0].sides, solutions[1].sides],
+                                }),
+                                _ => Err(TriangleError::NoSolution { sides, angles }),
+                            };
+                        }
                     }
                 }
                 (1, 3) => {
@@ -128,359 +526,680 @@ pub mod triangle_sides {
                         [None, Some(s), None] => Self::two_sides_calc(s, 1, angles_sines),
                         [None, None, Some(s)] => Self::two_sides_calc(s, 2, angles_sines),
                         _ => {
-                            return None;
+                            return Err(TriangleError::InsufficientData {
+                                sides,
+                                angles: given_angles,
+                            });
                         }
                     }
                 }
                 _ => {
-                    return None;
+                    return Err(TriangleError::InsufficientData {
+                        sides,
+                        angles: given_angles,
+                    });
                 }
             };
-            Self::new_from_sides(sides_complete)
-        }
-        pub fn new_from_sides(sides: [f64; 3]) -> Option<Triangle> {
-            let new = Self::new_blank(sides);
-            match new {
-                Some(mut new) => {
-                    new.perimeter_mut();
-                    new.area_mut();
-                    new.heights_mut();
-                    new.medians_mut();
-                    new.inradius_mut();
-                    new.circumradius_mut();
-                    new.sines_mut();
-                    new.cosines_mut();
-                    new.angles_mut();
-                    new.tangents_mut();
-                    Some(new)
+            Self::try_new_from_sides(sides_complete)
+        }
+        pub fn try_new_checked(
+            sides: [Option<T>; 3],
+            mut angles: [Option<T>; 3],
+            tolerance: T,
+        ) -> Result<GenericTriangle<T>, TriangleError<T>> {
+            let given_angles = angles;
+            for (index, angle) in angles.into_iter().enumerate() {
+                match angle {
+                    Some(angle) if angle <= T::zero() || angle >= T::pi() => {
+                        return Err(TriangleError::InvalidAngle { index, angle });
+                    }
+                    _ => (),
                 }
-                _ => None,
-            }
-        }
-        pub fn new_blank(sides: [f64; 3]) -> Option<Triangle> {
-            if Self::check_triangle_sides(sides) {
-                Some(Triangle {
-                    sides: [Some(sides[0]), Some(sides[1]), Some(sides[2])],
-                    heights: [None; 3],
-                    medians: [None; 3],
-                    angles: [None; 3],
-                    sines: [None; 3],
-                    cosines: [None; 3],
-                    tangents: [None; 3],
-                    perimeter: None,
-                    area: None,
-                    inradius: None,
-                    circumradius: None,
-                })
+            }
+            if let [Some(a0), Some(a1), Some(a2)] = angles {
+                let sum = a0 + a1 + a2;
+                if (sum - T::pi()).abs() > tolerance {
+                    return Err(TriangleError::AngleSum { angles, sum });
+                }
+                //one of the angles is redundant, it is calculated again in
+                //try_new() and cross-checked below
+                if let Some(index) = (0..3).find(|i| sides[*i].is_some()) {
+                    angles[index] = None;
+                }
+            }
+            let triangle = Self::try_new(sides, angles)?;
+            let mut mismatches: [Option<(T, T)>; 3] = [None; 3];
+            for index in 0..3 {
+                if let (Some(given), Some(derived)) = (given_angles[index], triangle.angle(index)) {
+                    if (given - derived).abs() > tolerance {
+                        mismatches[index] = Some((given, derived));
+                    }
+                }
+            }
+            if mismatches.iter().any(|mismatch| mismatch.is_some()) {
+                Err(TriangleError::InconsistentAngles { mismatches })
             } else {
-                None
+                Ok(triangle)
             }
         }
-        pub fn check_triangle_angles(angles: [f64; 3]) -> bool {
-            use core::f64::consts::PI;
-            const ROUNDING_ERROR: f64 = 0.0000000000001;
-            if (angles[0] + angles[1] + angles[2] - PI).abs() < ROUNDING_ERROR {
-                true
+        pub fn new_ssa(sides: [Option<T>; 3], angles: [Option<T>; 3]) -> Vec<GenericTriangle<T>> {
+            Self::try_new_ssa(sides, angles).unwrap_or_default()
+        }
+        pub fn try_new_ssa(
+            sides: [Option<T>; 3],
+            angles: [Option<T>; 3],
+        ) -> Result<Vec<GenericTriangle<T>>, TriangleError<T>> {
+            let missing = match sides {
+                [None, Some(_), Some(_)] => 0,
+                [Some(_), None, Some(_)] => 1,
+                [Some(_), Some(_), None] => 2,
+                _ => return Err(TriangleError::InsufficientData { sides, angles }),
+            };
+            let (opposite, angle) = match angles {
+                [Some(a), None, None] => (0, a),
+                [None, Some(a), None] => (1, a),
+                [None, None, Some(a)] => (2, a),
+                _ => return Err(TriangleError::InsufficientData { sides, angles }),
+            };
+            if angle <= T::zero() || angle >= T::pi() {
+                return Err(TriangleError::InvalidAngle {
+                    index: opposite,
+                    angle,
+                });
+            }
+            if opposite == missing {
+                //included angle, this is not ambiguous
+                let mut sides_complete = sides.map(|side| side.unwrap_or(T::zero()));
+                sides_complete[missing] = Self::third_side_calc(
+                    (
+                        sides_complete[(missing + 1) % 3],
+                        sides_complete[(missing + 2) % 3],
+                    ),
+                    angle,
+                );
+                return Ok(vec![Self::try_new_from_sides(sides_complete)?]);
+            }
+            let adjacent = 3 - missing - opposite;
+            let s_opposite = sides[opposite].unwrap();
+            let s_adjacent = sides[adjacent].unwrap();
+            for index in [opposite, adjacent] {
+                if sides[index].unwrap() <= T::zero() {
+                    return Err(TriangleError::NonPositiveSide {
+                        index,
+                        side: sides[index].unwrap(),
+                    });
+                }
+            }
+            //cosine law solved for the missing side gives a quadratic:
+            //x^2 - 2 * adjacent * cos(angle) * x + adjacent^2 - opposite^2 = 0
+            let projection = s_adjacent * angle.cos();
+            let altitude = s_adjacent * angle.sin();
+            let discriminant = s_opposite * s_opposite - altitude * altitude;
+            let roots = if discriminant.abs() <= T::rounding_error() * s_opposite * s_opposite {
+                //opposite side is tangent to the circle, the angle in front
+                //of the adjacent side is right and there is only one solution
+                vec![projection]
+            } else if discriminant < T::zero() {
+                Vec::new()
             } else {
-                false
+                let root = discriminant.sqrt();
+                vec![projection + root, projection - root]
+            };
+            let mut solutions = Vec::new();
+            for root in roots {
+                //a root that is zero up to rounding, e.g. when the opposite side
+                //equals the adjacent one, is not a triangle
+                if root <= T::rounding_error() * s_adjacent {
+                    continue;
+                }
+                let mut sides_complete = [T::zero(); 3];
+                sides_complete[missing] = root;
+                sides_complete[opposite] = s_opposite;
+                sides_complete[adjacent] = s_adjacent;
+                if let Some(triangle) = Self::new_from_sides(sides_complete) {
+                    solutions.push(triangle);
+                }
             }
+            Ok(solutions)
         }
-        pub fn check_triangle_sides(sides: [f64; 3]) -> bool {
-            for side in sides {
-                if side <= 0.0 {
-                    return false;
+        pub fn new_from_sides(sides: [T; 3]) -> Option<GenericTriangle<T>> {
+            Self::try_new_from_sides(sides).ok()
+        }
+        pub fn try_new_from_sides(sides: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+            let new = Self::try_new_blank(sides)?;
+            new.perimeter();
+            new.area();
+            new.heights();
+            new.medians();
+            new.bisectors();
+            new.inradius();
+            new.circumradius();
+            new.exradii();
+            new.sines();
+            new.cosines();
+            new.angles();
+            new.tangents();
+            new.brocard_angle();
+            Ok(new)
+        }
+        pub fn new_blank(sides: [T; 3]) -> Option<GenericTriangle<T>> {
+            Self::try_new_blank(sides).ok()
+        }
+        pub fn try_new_blank(sides: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+            Self::validate_sides(sides)?;
+            Ok(GenericTriangle::with_sides(sides))
+        }
+        pub fn check_triangle_angles(angles: [T; 3]) -> bool {
+            Self::validate_angles(angles).is_ok()
+        }
+        pub fn validate_angles(angles: [T; 3]) -> Result<(), TriangleError<T>> {
+            for (index, angle) in angles.into_iter().enumerate() {
+                if angle <= T::zero() || angle >= T::pi() {
+                    return Err(TriangleError::InvalidAngle { index, angle });
                 }
             }
-            let maxside = Self::max_side(sides);
-            if maxside > sides[0] + sides[1] + sides[2] - maxside {
-                false
+            let sum = angles[0] + angles[1] + angles[2];
+            if (sum - T::pi()).abs() < T::rounding_error() {
+                Ok(())
             } else {
-                true
+                Err(TriangleError::AngleSum {
+                    angles: [Some(angles[0]), Some(angles[1]), Some(angles[2])],
+                    sum,
+                })
             }
         }
-        pub fn max_side(sides: [f64; 3]) -> f64 {
-            let mut maxside = 0.0;
-            for side in sides {
-                if side > maxside {
-                    maxside = side;
+        pub fn check_triangle_sides(sides: [T; 3]) -> bool {
+            Self::validate_sides(sides).is_ok()
+        }
+        pub fn validate_sides(sides: [T; 3]) -> Result<(), TriangleError<T>> {
+            for (index, side) in sides.into_iter().enumerate() {
+                //sides that cannot be compared with zero are rejected as well
+                if side.partial_cmp(&T::zero()) != Some(std::cmp::Ordering::Greater) {
+                    return Err(TriangleError::NonPositiveSide { index, side });
                 }
             }
+            //every side is at most the other two together, each side appears in
+            //its margin only once, so intervals are not widened
+            let [s0, s1, s2] = sides;
+            let margins = [s1 + s2 - s0, s2 + s0 - s1, s0 + s1 - s2];
+            if margins.iter().all(|&margin| margin >= T::zero()) {
+                Ok(())
+            } else {
+                Err(TriangleError::TriangleInequality { sides })
+            }
+        }
+        pub fn max_side(sides: [T; 3]) -> T {
+            let mut maxside = T::zero();
+            for side in sides {
+                maxside = maxside.max(side);
+            }
             maxside
         }
-        fn check_index(index: usize) -> bool {
-            match index {
-                0..3 => true,
-                _ => false,
+        //special function only designed to calculate third angle in new() function
+        fn special_third_angle(angles: [Option<T>; 3]) -> [Option<T>; 3] {
+            let pi = T::pi();
+            match angles {
+                [None, Some(a2), Some(a3)] => [Some(pi - a2 - a3), Some(a2), Some(a3)],
+                [Some(a1), None, Some(a3)] => [Some(a1), Some(pi - a1 - a3), Some(a3)],
+                [Some(a1), Some(a2), None] => [Some(a1), Some(a2), Some(pi - a1 - a2)],
+                _ => angles,
             }
         }
-        pub fn side(&self, index: usize) -> Option<f64> {
-            if Self::check_index(index) {
-                self.sides[index]
+        fn third_side_calc(sides: (T, T), angle: T) -> T {
+            //cosine law for third side
+            let two = T::from_f64(2.0);
+            (sides.0 * sides.0 + sides.1 * sides.1 - two * sides.0 * sides.1 * angle.cos()).sqrt()
+        }
+        fn area_calc(sides: [T; 3]) -> T {
+            //heron's formula rearranged by Kahan, it does not lose precision
+            //for needle-like triangles, the parentheses must stay as they are
+            let mut sorted = sides;
+            sorted.sort_by(|x, y| y.partial_cmp(x).unwrap_or(std::cmp::Ordering::Equal));
+            let [a, b, c] = sorted;
+            let product = (a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c));
+            product.max(T::zero()).sqrt() / T::from_f64(4.0)
+        }
+        fn angle_calc(opposite: T, sides: (T, T)) -> T {
+            //Kahan's formula for the angle in front of the opposite side,
+            //acos of the cosine loses precision for very small and very big angles
+            let (a, b) = if sides.0 >= sides.1 {
+                sides
             } else {
-                None
+                (sides.1, sides.0)
+            };
+            let c = opposite;
+            let mu = if b >= c { c - (a - b) } else { b - (a - c) };
+            let numerator = ((a - b) + c) * mu;
+            let denominator = (a + (b + c)) * ((a - c) + b);
+            T::from_f64(2.0) * (numerator.max(T::zero()) / denominator).sqrt().atan()
+        }
+        fn two_sides_calc(side: T, index: usize, sines: [T; 3]) -> [T; 3] {
+            //sine law for second and third side
+            let mut sides: [Option<T>; 3] = [None; 3];
+            for i in 0..3 {
+                if i != index {
+                    sides[i] = Some(side * sines[i] / sines[index]);
+                } else {
+                    sides[i] = Some(side);
+                }
             }
+            [sides[0].unwrap(), sides[1].unwrap(), sides[2].unwrap()]
         }
-        pub fn sides(&self) -> [Option<f64>; 3] {
-            self.sides
+    }
+
+    impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+        fn check_index(index: usize) -> bool {
+            matches!(index, 0..3)
         }
-        pub fn perimeter(&self) -> Option<f64> {
-            match (self.perimeter, self.sides) {
-                (Some(perimeter), _) => Some(perimeter),
-                (None, [Some(s1), Some(s2), Some(s3)]) => Some(s1 + s2 + s3),
-                _ => None,
+        fn with_sides(sides: [T; 3]) -> GenericTriangle<T, S> {
+            GenericTriangle {
+                sides,
+                heights: Default::default(),
+                medians: Default::default(),
+                bisectors: Default::default(),
+                exradii: Default::default(),
+                angles: Default::default(),
+                sines: Default::default(),
+                cosines: Default::default(),
+                tangents: Default::default(),
+                perimeter: S::default(),
+                area: S::default(),
+                inradius: S::default(),
+                circumradius: S::default(),
+                brocard_angle: S::default(),
+                placement: None,
             }
         }
-        pub fn perimeter_mut(&mut self) -> Option<f64> {
-            self.perimeter = self.perimeter();
-            self.perimeter
+        fn convert<S2: Slot<T>>(&self) -> GenericTriangle<T, S2> {
+            //moves the already calculated values to a different storage
+            fn copy<T, S: Slot<T>, S2: Slot<T>>(from: &S) -> S2 {
+                let to = S2::default();
+                if let Some(value) = from.get() {
+                    to.get_or_init(|| value);
+                }
+                to
+            }
+            GenericTriangle {
+                sides: self.sides,
+                heights: self.heights.each_ref().map(copy),
+                medians: self.medians.each_ref().map(copy),
+                bisectors: self.bisectors.each_ref().map(copy),
+                exradii: self.exradii.each_ref().map(copy),
+                angles: self.angles.each_ref().map(copy),
+                sines: self.sines.each_ref().map(copy),
+                cosines: self.cosines.each_ref().map(copy),
+                tangents: self.tangents.each_ref().map(copy),
+                perimeter: copy(&self.perimeter),
+                area: copy(&self.area),
+                inradius: copy(&self.inradius),
+                circumradius: copy(&self.circumradius),
+                brocard_angle: copy(&self.brocard_angle),
+                placement: self.placement,
+            }
         }
-        pub fn area(&self) -> Option<f64> {
-            match (self.area, self.perimeter(), self.sides) {
-                (Some(area), _, _) => Some(area),
-                (None, Some(p), [Some(s0), Some(s1), Some(s2)]) => {
-                    let sp = p / 2.0; // semi-perimeter
-                    Some((sp * (sp - s0) * (sp - s1) * (sp - s2)).sqrt()) // heron's formula
+        fn clear_cache(&mut self) {
+            for slots in [
+                &mut self.heights,
+                &mut self.medians,
+                &mut self.bisectors,
+                &mut self.exradii,
+                &mut self.angles,
+                &mut self.sines,
+                &mut self.cosines,
+                &mut self.tangents,
+            ] {
+                for slot in slots {
+                    slot.take();
                 }
-                _ => None,
+            }
+            for slot in [
+                &mut self.perimeter,
+                &mut self.area,
+                &mut self.inradius,
+                &mut self.circumradius,
+                &mut self.brocard_angle,
+            ] {
+                slot.take();
             }
         }
-        pub fn area_mut(&mut self) -> Option<f64> {
-            if self.perimeter == None {
-                self.perimeter_mut();
+        fn scale_cache(&mut self, factor: T) {
+            fn rescale<T: Float, S: Slot<T>>(slot: &mut S, factor: T) {
+                if let Some(value) = slot.take() {
+                    slot.get_or_init(|| value * factor);
+                }
+            }
+            //angles and their functions do not depend on the scale
+            for slots in [
+                &mut self.heights,
+                &mut self.medians,
+                &mut self.bisectors,
+                &mut self.exradii,
+            ] {
+                for slot in slots {
+                    rescale(slot, factor);
+                }
             }
-            self.area = self.area();
-            self.area
+            for slot in [
+                &mut self.perimeter,
+                &mut self.inradius,
+                &mut self.circumradius,
+            ] {
+                rescale(slot, factor);
+            }
+            rescale(&mut self.area, factor * factor);
         }
-        pub fn height(&self, index: usize) -> Option<f64> {
+        pub fn set_side(&mut self, index: usize, length: T) -> Result<(), TriangleError<T>> {
             if !Self::check_index(index) {
-                return None;
+                return Err(TriangleError::InvalidIndex { index });
             }
-            match (self.heights[index], self.area(), self.sides[index]) {
-                (Some(h), _, _) => Some(h),
-                (None, Some(a), Some(s)) => Some(a * 2.0 / s),
-                _ => None,
+            let mut sides = self.sides;
+            sides[index] = length;
+            self.set_sides(sides)
+        }
+        pub fn set_sides(&mut self, sides: [T; 3]) -> Result<(), TriangleError<T>> {
+            GenericTriangle::validate_sides(sides)?;
+            self.sides = sides;
+            //every property depends on all three sides
+            self.clear_cache();
+            //and the points do not match the new sides
+            self.placement = None;
+            Ok(())
+        }
+        pub fn scale(&mut self, factor: T) -> Result<(), TriangleError<T>> {
+            let sides = self.sides.map(|side| side * factor);
+            GenericTriangle::validate_sides(sides)?;
+            self.sides = sides;
+            self.scale_cache(factor);
+            self.placement = self.placement.map(|placement| placement.scale(factor));
+            Ok(())
+        }
+        pub fn side(&self, index: usize) -> Option<T> {
+            if Self::check_index(index) {
+                Some(self.sides[index])
+            } else {
+                None
             }
         }
-        pub fn height_mut(&mut self, index: usize) -> Option<f64> {
+        pub fn sides(&self) -> [Option<T>; 3] {
+            [
+                Some(self.sides[0]),
+                Some(self.sides[1]),
+                Some(self.sides[2]),
+            ]
+        }
+        pub fn perimeter(&self) -> Option<T> {
+            let [s0, s1, s2] = self.sides;
+            Some(self.perimeter.get_or_init(|| s0 + s1 + s2))
+        }
+        pub fn perimeter_mut(&mut self) -> Option<T> {
+            self.perimeter()
+        }
+        pub fn area(&self) -> Option<T> {
+            Some(
+                self.area
+                    .get_or_init(|| GenericTriangle::area_calc(self.sides)),
+            )
+        }
+        pub fn area_mut(&mut self) -> Option<T> {
+            self.area()
+        }
+        pub fn height(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            if self.area == None {
-                self.area_mut();
-            }
-            self.heights[index] = self.height(index);
-            self.heights[index]
+            let a = self.area()?;
+            Some(self.heights[index].get_or_init(|| a * T::from_f64(2.0) / self.sides[index]))
+        }
+        pub fn height_mut(&mut self, index: usize) -> Option<T> {
+            self.height(index)
         }
-        pub fn heights(&self) -> [Option<f64>; 3] {
+        pub fn heights(&self) -> [Option<T>; 3] {
             [self.height(0), self.height(1), self.height(2)]
         }
-        pub fn heights_mut(&mut self) -> [Option<f64>; 3] {
-            [self.height_mut(0), self.height_mut(1), self.height_mut(2)]
+        pub fn heights_mut(&mut self) -> [Option<T>; 3] {
+            self.heights()
         }
-        pub fn median(&self, index: usize) -> Option<f64> {
+        pub fn median(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            match (self.medians[index], self.sides) {
-                (Some(m), _) => Some(m),
-                (None, [Some(s0), Some(s1), Some(s2)]) => Some(
-                    ((2.0 * (s0 * s0 + s1 * s1 + s2 * s2)
-                        - 3.0 * self.sides[index].unwrap() * self.sides[index].unwrap())
-                        / 4.0)
-                        .sqrt(),
-                ),
+            let [s0, s1, s2] = self.sides;
+            let s = self.sides[index];
+            Some(self.medians[index].get_or_init(|| {
                 //formula for median
-                _ => None,
-            }
+                ((T::from_f64(2.0) * (s0 * s0 + s1 * s1 + s2 * s2) - T::from_f64(3.0) * s * s)
+                    / T::from_f64(4.0))
+                .sqrt()
+            }))
+        }
+        pub fn median_mut(&mut self, index: usize) -> Option<T> {
+            self.median(index)
         }
-        pub fn median_mut(&mut self, index: usize) -> Option<f64> {
+        pub fn medians(&self) -> [Option<T>; 3] {
+            [self.median(0), self.median(1), self.median(2)]
+        }
+        pub fn medians_mut(&mut self) -> [Option<T>; 3] {
+            self.medians()
+        }
+        pub fn bisector(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            self.medians[index] = self.median(index);
-            self.medians[index]
+            let s = self.sides[index];
+            let s1 = self.sides[(index + 1) % 3];
+            let s2 = self.sides[(index + 2) % 3];
+            Some(self.bisectors[index].get_or_init(|| {
+                //formula for angle bisector, starts at the vertex opposite the side
+                (s1 * s2 * (s1 + s2 - s) * (s1 + s2 + s)).sqrt() / (s1 + s2)
+            }))
         }
-        pub fn medians(&self) -> [Option<f64>; 3] {
-            [self.median(0), self.median(1), self.median(2)]
+        pub fn bisector_mut(&mut self, index: usize) -> Option<T> {
+            self.bisector(index)
         }
-        pub fn medians_mut(&mut self) -> [Option<f64>; 3] {
-            [self.median_mut(0), self.median_mut(1), self.median_mut(2)]
+        pub fn bisectors(&self) -> [Option<T>; 3] {
+            [self.bisector(0), self.bisector(1), self.bisector(2)]
         }
-        pub fn circumradius(&self) -> Option<f64> {
-            match (self.circumradius, self.area(), self.sides) {
-                (Some(r), _, _) => Some(r),
-                (None, Some(a), [Some(s0), Some(s1), Some(s2)]) => Some(s0 * s1 * s2 / a / 4.0),
-                _ => None,
-            }
+        pub fn bisectors_mut(&mut self) -> [Option<T>; 3] {
+            self.bisectors()
         }
-        pub fn circumradius_mut(&mut self) -> Option<f64> {
-            if self.area == None {
-                self.area_mut();
+        pub fn bisector_ratio(&self, index: usize) -> Option<T> {
+            if !Self::check_index(index) {
+                return None;
             }
-            self.circumradius = self.circumradius();
-            self.circumradius
+            //angle bisector theorem, the part next to the vertex (index + 1) % 3
+            //divided by the part next to the vertex (index + 2) % 3
+            Some(self.sides[(index + 2) % 3] / self.sides[(index + 1) % 3])
         }
-        pub fn inradius(&self) -> Option<f64> {
-            match (self.inradius, self.area(), self.perimeter()) {
-                (Some(r), _, _) => Some(r),
-                (None, Some(a), Some(p)) => Some(a * 2.0 / p),
-                _ => None,
-            }
+        pub fn bisector_ratios(&self) -> [Option<T>; 3] {
+            [
+                self.bisector_ratio(0),
+                self.bisector_ratio(1),
+                self.bisector_ratio(2),
+            ]
         }
-        pub fn inradius_mut(&mut self) -> Option<f64> {
-            if self.area == None {
-                self.area_mut();
+        pub fn bisector_foot(&self, index: usize) -> Option<(T, T)> {
+            if !Self::check_index(index) {
+                return None;
             }
-            self.inradius = self.inradius();
-            self.inradius
+            let s = self.sides[index];
+            let s1 = self.sides[(index + 1) % 3];
+            let s2 = self.sides[(index + 2) % 3];
+            Some((s * s2 / (s1 + s2), s * s1 / (s1 + s2)))
         }
-        pub fn sine(&self, index: usize) -> Option<f64> {
+        pub fn bisector_feet(&self) -> [Option<(T, T)>; 3] {
+            [
+                self.bisector_foot(0),
+                self.bisector_foot(1),
+                self.bisector_foot(2),
+            ]
+        }
+        pub fn circumradius(&self) -> Option<T> {
+            let [s0, s1, s2] = self.sides;
+            let a = self.area()?;
+            Some(
+                self.circumradius
+                    .get_or_init(|| s0 * s1 * s2 / a / T::from_f64(4.0)),
+            )
+        }
+        pub fn circumradius_mut(&mut self) -> Option<T> {
+            self.circumradius()
+        }
+        pub fn inradius(&self) -> Option<T> {
+            let a = self.area()?;
+            let p = self.perimeter()?;
+            Some(self.inradius.get_or_init(|| a * T::from_f64(2.0) / p))
+        }
+        pub fn inradius_mut(&mut self) -> Option<T> {
+            self.inradius()
+        }
+        pub fn exradius(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            match (self.sines[index], self.sides, self.area()) {
-                (Some(sin), _, _) => Some(sin),
-                (None, [Some(s0), Some(s1), Some(s2)], Some(a)) => {
-                    Some(a * 2.0 * self.sides[index].unwrap() / (s0 * s1 * s2))
+            let a = self.area()?;
+            if a == T::zero() {
+                //a degenerate triangle has no excircles
+                return None;
+            }
+            //semi-perimeter
+            let sp = self.perimeter()? / T::from_f64(2.0);
+            //radius of the excircle tangent to the side of the same index
+            Some(self.exradii[index].get_or_init(|| a / (sp - self.sides[index])))
+        }
+        pub fn exradius_mut(&mut self, index: usize) -> Option<T> {
+            self.exradius(index)
+        }
+        pub fn exradii(&self) -> [Option<T>; 3] {
+            [self.exradius(0), self.exradius(1), self.exradius(2)]
+        }
+        pub fn exradii_mut(&mut self) -> [Option<T>; 3] {
+            self.exradii()
+        }
+        pub fn check_exradii(&self, tolerance: T) -> bool {
+            //r0 + r1 + r2 = 4 * circumradius + inradius
+            //1 / r0 + 1 / r1 + 1 / r2 = 1 / inradius
+            match (self.exradii(), self.circumradius(), self.inradius()) {
+                ([Some(r0), Some(r1), Some(r2)], Some(big_r), Some(r)) => {
+                    let one = T::one();
+                    let sum = T::from_f64(4.0) * big_r + r;
+                    (r0 + r1 + r2 - sum).abs() <= tolerance * sum
+                        && (one / r0 + one / r1 + one / r2 - one / r).abs() <= tolerance / r
                 }
-                _ => None,
+                _ => false,
             }
         }
-        pub fn sine_mut(&mut self, index: usize) -> Option<f64> {
+        pub fn sine(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            if self.area == None {
-                self.area_mut();
-            }
-            self.sines[index] = self.sine(index);
-            self.sines[index]
+            let [s0, s1, s2] = self.sides;
+            let a = self.area()?;
+            Some(
+                self.sines[index]
+                    .get_or_init(|| a * T::from_f64(2.0) * self.sides[index] / (s0 * s1 * s2)),
+            )
         }
-        pub fn sines(&self) -> [Option<f64>; 3] {
+        pub fn sine_mut(&mut self, index: usize) -> Option<T> {
+            self.sine(index)
+        }
+        pub fn sines(&self) -> [Option<T>; 3] {
             [self.sine(0), self.sine(1), self.sine(2)]
         }
-        pub fn sines_mut(&mut self) -> [Option<f64>; 3] {
-            [self.sine_mut(0), self.sine_mut(1), self.sine_mut(2)]
+        pub fn sines_mut(&mut self) -> [Option<T>; 3] {
+            self.sines()
         }
-        pub fn cosine(&self, index: usize) -> Option<f64> {
+        pub fn cosine(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            match (self.cosines[index], self.sides) {
-                (Some(cos), _) => Some(cos),
-                (None, [Some(s0), Some(s1), Some(s2)]) => Some(
-                    (s0 * s0 + s1 * s1 + s2 * s2
-                        - 2.0 * self.sides[index].unwrap() * self.sides[index].unwrap())
-                        * self.sides[index].unwrap()
-                        / (2.0 * s0 * s1 * s2),
-                ),
+            let [s0, s1, s2] = self.sides;
+            let s = self.sides[index];
+            let two = T::from_f64(2.0);
+            Some(self.cosines[index].get_or_init(|| {
                 //form law of cosines - solution for cos
-                _ => None,
-            }
+                (s0 * s0 + s1 * s1 + s2 * s2 - two * s * s) * s / (two * s0 * s1 * s2)
+            }))
         }
-        pub fn cosine_mut(&mut self, index: usize) -> Option<f64> {
-            if !Self::check_index(index) {
-                return None;
-            }
-            self.cosines[index] = self.cosine(index);
-            self.cosines[index]
+        pub fn cosine_mut(&mut self, index: usize) -> Option<T> {
+            self.cosine(index)
         }
-        pub fn cosines(&self) -> [Option<f64>; 3] {
+        pub fn cosines(&self) -> [Option<T>; 3] {
             [self.cosine(0), self.cosine(1), self.cosine(2)]
         }
-        pub fn cosines_mut(&mut self) -> [Option<f64>; 3] {
-            [self.cosine_mut(0), self.cosine_mut(1), self.cosine_mut(2)]
+        pub fn cosines_mut(&mut self) -> [Option<T>; 3] {
+            self.cosines()
         }
-        pub fn angle(&self, index: usize) -> Option<f64> {
+        pub fn angle(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            match (self.angles[index], self.cosine(index)) {
-                (Some(a), _) => Some(a),
-                (None, Some(cos)) => Some(cos.acos()),
-                _ => None,
-            }
+            let s1 = self.sides[(index + 1) % 3];
+            let s2 = self.sides[(index + 2) % 3];
+            Some(
+                self.angles[index]
+                    .get_or_init(|| GenericTriangle::angle_calc(self.sides[index], (s1, s2))),
+            )
         }
-        pub fn angle_mut(&mut self, index: usize) -> Option<f64> {
-            if !Self::check_index(index) {
-                return None;
-            }
-            if self.cosines[index] == None {
-                self.cosine_mut(index);
-            }
-            self.angles[index] = self.angle(index);
-            self.angles[index]
+        pub fn angle_mut(&mut self, index: usize) -> Option<T> {
+            self.angle(index)
         }
-        pub fn angles(&self) -> [Option<f64>; 3] {
+        pub fn angles(&self) -> [Option<T>; 3] {
             [self.angle(0), self.angle(1), self.angle(2)]
         }
-        pub fn angles_mut(&mut self) -> [Option<f64>; 3] {
-            [self.angle_mut(0), self.angle_mut(1), self.angle_mut(2)]
+        pub fn angles_mut(&mut self) -> [Option<T>; 3] {
+            self.angles()
         }
-        pub fn tangent(&self, index: usize) -> Option<f64> {
+        pub fn tangent(&self, index: usize) -> Option<T> {
             if !Self::check_index(index) {
                 return None;
             }
-            match (self.tangents[index], self.angle(index)) {
-                (Some(tan), _) => Some(tan),
-                (None, Some(a)) => Some(a.tan()),
-                _ => None,
-            }
+            let a = self.angle(index)?;
+            Some(self.tangents[index].get_or_init(|| a.tan()))
         }
-        pub fn tangent_mut(&mut self, index: usize) -> Option<f64> {
-            if !Self::check_index(index) {
-                return None;
-            }
-            if self.angles[index] == None {
-                self.angle_mut(index);
-            }
-            self.tangents[index] = self.tangent(index);
-            self.tangents[index]
+        pub fn tangent_mut(&mut self, index: usize) -> Option<T> {
+            self.tangent(index)
         }
-        pub fn tangents(&self) -> [Option<f64>; 3] {
+        pub fn tangents(&self) -> [Option<T>; 3] {
             [self.tangent(0), self.tangent(1), self.tangent(2)]
         }
-        pub fn tangents_mut(&mut self) -> [Option<f64>; 3] {
-            [
-                self.tangent_mut(0),
-                self.tangent_mut(1),
-                self.tangent_mut(2),
-            ]
+        pub fn tangents_mut(&mut self) -> [Option<T>; 3] {
+            self.tangents()
         }
-        //special function only designed to calculate third angle in new() function
-        fn special_third_angle(angles: [Option<f64>; 3]) -> [Option<f64>; 3] {
-            use core::f64::consts::PI;
-            match angles {
-                [None, Some(a2), Some(a3)] => [Some(PI - a2 - a3), Some(a2), Some(a3)],
-                [Some(a1), None, Some(a3)] => [Some(a1), Some(PI - a1 - a3), Some(a3)],
-                [Some(a1), Some(a2), None] => [Some(a1), Some(a2), Some(PI - a1 - a2)],
-                _ => angles,
-            }
-        }
-        fn third_side_calc(sides: (f64, f64), angle: f64) -> f64 {
-            //cosine law for third side
-            (sides.0 * sides.0 + sides.1 * sides.1 - 2.0 * sides.0 * sides.1 * angle.cos()).sqrt()
-        }
-        fn two_sides_calc(side: f64, index: usize, sines: [f64; 3]) -> [f64; 3] {
-            //sine law for second and third side
-            let mut sides: [Option<f64>; 3] = [None; 3];
-            for i in 0..3 {
-                if i != index {
-                    sides[i] = Some(side * sines[i] / sines[index]);
-                } else {
-                    sides[i] = Some(side);
-                }
-            }
-            [sides[0].unwrap(), sides[1].unwrap(), sides[2].unwrap()]
+    }
+
+    //inside the module, so the tests can look at the cache slots
+    #[cfg(test)]
+    mod tests {
+        use super::testing::ROUNDING_ERROR;
+        use super::{Slot, SyncTriangle, Triangle};
+
+        #[test]
+        fn test_cache() -> Result<(), String> {
+            use std::sync::Arc;
+            let blank = Triangle::new_blank([3.0, 4.0, 5.0]).unwrap();
+            assert_eq!(Slot::get(&blank.area), None);
+            assert!((blank.height(2).unwrap() - 2.4).abs() < ROUNDING_ERROR);
+            assert_eq!(Slot::get(&blank.area), Some(6.0));
+            assert_eq!(Slot::get(&blank.heights[0]), None);
+            let shared = Arc::new(SyncTriangle::from(blank));
+            let handles: Vec<_> = (0..3)
+                .map(|i| {
+                    let shared = Arc::clone(&shared);
+                    std::thread::spawn(move || shared.angle(i).unwrap())
+                })
+                .collect();
+            let angles: Vec<f64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+            assert!((angles.iter().sum::<f64>() - core::f64::consts::PI).abs() < ROUNDING_ERROR);
+            assert!((shared.area().unwrap() - 6.0).abs() < ROUNDING_ERROR);
+            Ok(())
         }
     }
 }
 
-
 #[cfg(test)]
 mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
     use crate::triangle_sides::Triangle;
-    const ROUNDING_ERROR: f64 = 0.0000000000001;
 
     #[test]
     fn test_egyptian() -> Result<(), String> {
@@ -523,4 +1242,261 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_errors() -> Result<(), String> {
+        use crate::triangle_sides::TriangleError;
+        assert_eq!(
+            Triangle::try_new_from_sides([3.0, -4.0, 5.0]).unwrap_err(),
+            TriangleError::NonPositiveSide {
+                index: 1,
+                side: -4.0
+            }
+        );
+        assert_eq!(
+            Triangle::try_new_blank([1.0, 2.0, 5.0]).unwrap_err(),
+            TriangleError::TriangleInequality {
+                sides: [1.0, 2.0, 5.0]
+            }
+        );
+        assert_eq!(
+            Triangle::try_new([Some(1.0), None, None], [None, None, Some(1.0)]).unwrap_err(),
+            TriangleError::InsufficientData {
+                sides: [Some(1.0), None, None],
+                angles: [None, None, Some(1.0)]
+            }
+        );
+        assert_eq!(
+            Triangle::try_new([Some(1.0), None, None], [None, None, Some(1.0)])
+                .unwrap_err()
+                .to_string(),
+            "sides 1, unknown, unknown and angles unknown, unknown, 1 are not enough to construct a triangle"
+        );
+        assert!(matches!(
+            Triangle::try_new([None, Some(2.0), Some(1.5)], [None, None, Some(0.6)]),
+            Err(TriangleError::Ambiguous { .. })
+        ));
+        assert!(matches!(
+            Triangle::try_new([None, Some(2.0), Some(0.5)], [None, None, Some(0.6)]),
+            Err(TriangleError::NoSolution { .. })
+        ));
+        assert!(matches!(
+            Triangle::validate_angles([1.0, 1.0, 1.0]),
+            Err(TriangleError::AngleSum { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked() -> Result<(), String> {
+        use crate::triangle_sides::TriangleError;
+        use core::f64::consts::PI;
+        let right = (4.0_f64 / 5.0).acos();
+        let checked = Triangle::try_new_checked(
+            [Some(3.0), Some(4.0), Some(5.0)],
+            [None, None, Some(PI / 2.0)],
+            0.001,
+        )
+        .unwrap();
+        assert!((checked.area().unwrap() - 6.0).abs() < ROUNDING_ERROR);
+        assert_eq!(
+            Triangle::try_new_checked(
+                [Some(3.0), Some(4.0), Some(5.0)],
+                [Some(right), None, Some(1.5)],
+                0.001
+            )
+            .unwrap_err(),
+            TriangleError::InconsistentAngles {
+                mismatches: [None, None, Some((1.5, PI / 2.0))]
+            }
+        );
+        //angles within tolerance are accepted even if they do not sum exactly to pi
+        assert!(Triangle::try_new_checked(
+            [Some(3.0), None, None],
+            [Some(right), Some(PI / 2.0 - right + 0.0001), Some(PI / 2.0)],
+            0.001
+        )
+        .is_ok());
+        assert!(matches!(
+            Triangle::try_new_checked(
+                [Some(3.0), None, None],
+                [Some(1.0), Some(1.0), Some(1.0)],
+                0.001
+            ),
+            Err(TriangleError::AngleSum { .. })
+        ));
+        //new() no longer creates a triangle from angles that do not sum to pi
+        assert!(
+            Triangle::new([Some(1.0), None, None], [Some(1.0), Some(1.0), Some(1.0)]).is_none()
+        );
+        assert!(matches!(
+            Triangle::try_new([Some(1.0), None, None], [Some(2.0), Some(2.0), None]),
+            Err(TriangleError::AngleSum { sum, .. }) if (sum - 4.0).abs() < ROUNDING_ERROR
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_setters() -> Result<(), String> {
+        use crate::triangle_sides::TriangleError;
+        let mut triangle = Triangle::new_from_sides([3.0, 4.0, 5.0]).unwrap();
+        triangle.scale(2.0).unwrap();
+        assert!((triangle.area().unwrap() - 24.0).abs() < ROUNDING_ERROR);
+        assert!((triangle.circumradius().unwrap() - 5.0).abs() < ROUNDING_ERROR);
+        assert!((triangle.height(2).unwrap() - 4.8).abs() < ROUNDING_ERROR);
+        assert!((triangle.angle(2).unwrap().to_degrees() - 90.0).abs() < ROUNDING_ERROR);
+        triangle.set_side(2, 6.0).unwrap();
+        let expected = Triangle::new_from_sides([6.0, 8.0, 6.0]).unwrap();
+        assert_eq!(triangle.sides(), expected.sides());
+        assert!((triangle.area().unwrap() - expected.area().unwrap()).abs() < ROUNDING_ERROR);
+        assert!((triangle.angle(1).unwrap() - expected.angle(1).unwrap()).abs() < ROUNDING_ERROR);
+        assert_eq!(
+            triangle.set_side(0, 20.0).unwrap_err(),
+            TriangleError::TriangleInequality {
+                sides: [20.0, 8.0, 6.0]
+            }
+        );
+        assert_eq!(
+            triangle.set_side(3, 1.0).unwrap_err(),
+            TriangleError::InvalidIndex { index: 3 }
+        );
+        assert!(triangle.scale(-1.0).is_err());
+        assert_eq!(triangle.sides(), expected.sides());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bisectors() -> Result<(), String> {
+        let egyptian = Triangle::new_from_sides([3.0, 4.0, 5.0]).unwrap();
+        assert!((egyptian.bisector(2).unwrap() - 288.0_f64.sqrt() / 7.0).abs() < ROUNDING_ERROR);
+        assert!((egyptian.bisector_ratio(2).unwrap() - 4.0 / 3.0).abs() < ROUNDING_ERROR);
+        let (from_0, from_1) = egyptian.bisector_foot(2).unwrap();
+        assert!((from_0 - 20.0 / 7.0).abs() < ROUNDING_ERROR);
+        assert!((from_1 - 15.0 / 7.0).abs() < ROUNDING_ERROR);
+        //bisector from the vertex 0 has the length of the leg 4 over cos of half the angle
+        let half = egyptian.angle(0).unwrap() / 2.0;
+        assert!((egyptian.bisector(0).unwrap() - 4.0 / half.cos()).abs() < ROUNDING_ERROR);
+        let blank = Triangle::new_blank([3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(blank.bisectors(), egyptian.bisectors());
+        assert_eq!(blank.bisector(3), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exradii() -> Result<(), String> {
+        let egyptian = Triangle::new_from_sides([3.0, 4.0, 5.0]).unwrap();
+        assert!((egyptian.exradius(0).unwrap() - 2.0).abs() < ROUNDING_ERROR);
+        assert!((egyptian.exradius(1).unwrap() - 3.0).abs() < ROUNDING_ERROR);
+        assert!((egyptian.exradius(2).unwrap() - 6.0).abs() < ROUNDING_ERROR);
+        assert!(egyptian.check_exradii(ROUNDING_ERROR));
+        let mut obtuse = Triangle::new_blank([2.0, 3.0, 4.5]).unwrap();
+        assert!(obtuse.check_exradii(ROUNDING_ERROR));
+        let before = obtuse.exradii_mut();
+        obtuse.scale(3.0).unwrap();
+        for (i, radius) in before.into_iter().enumerate() {
+            assert!((obtuse.exradius(i).unwrap() - 3.0 * radius.unwrap()).abs() < ROUNDING_ERROR);
+        }
+        let degenerate = Triangle::new_from_sides([1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(degenerate.exradii(), [None, None, None]);
+        assert!(!degenerate.check_exradii(ROUNDING_ERROR));
+        Ok(())
+    }
+
+    #[test]
+    fn test_needle() -> Result<(), String> {
+        for leg in [1.0_f64, 1e10] {
+            for base in [1e-5, 1e-10, 1e-17] {
+                let needle = Triangle::new_from_sides([leg, leg, leg * base]).unwrap();
+                let c = leg * base;
+                let area = c / 4.0 * (4.0 * leg * leg - c * c).sqrt();
+                assert!(((needle.area().unwrap() - area) / area).abs() < ROUNDING_ERROR);
+                let angle = 2.0 * (base / 2.0).asin();
+                assert!(((needle.angle(2).unwrap() - angle) / angle).abs() < ROUNDING_ERROR);
+                assert!(needle.circumradius().unwrap().is_finite());
+            }
+        }
+        //heron's formula would give 0.0 here
+        let flat = Triangle::new_from_sides([1.0, 1.0, 1e-17]).unwrap();
+        assert!(flat.area().unwrap() > 0.0);
+        //acos would lose half of the digits for angle close to pi
+        let base = 2.0 - 1e-12;
+        let delta: f64 = 2.0 - base;
+        let obtuse = Triangle::new_from_sides([1.0, 1.0, base]).unwrap();
+        let expected = 4.0 * (delta.sqrt() / 2.0).asin();
+        let computed = core::f64::consts::PI - obtuse.angle(2).unwrap();
+        assert!(((computed - expected) / expected).abs() < 0.000000001);
+        let sum: f64 = obtuse.angles().iter().flatten().sum();
+        assert!((sum - core::f64::consts::PI).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_f32() -> Result<(), String> {
+        use crate::triangle_sides::{Float, GenericTriangle};
+        let egyptian = GenericTriangle::new_from_sides([3.0_f32, 4.0, 5.0]).unwrap();
+        assert!((egyptian.angle(2).unwrap().to_degrees() - 90.0).abs() < f32::rounding_error());
+        assert!((egyptian.area().unwrap() - 6.0).abs() < f32::rounding_error());
+        assert!((egyptian.circumradius().unwrap() - 2.5).abs() < f32::rounding_error());
+        let acute = GenericTriangle::new([Some(1.0_f32), Some(1.5), None], [None, None, Some(1.0)])
+            .unwrap();
+        assert!((acute.angle(1).unwrap() - 1.42).abs() < 0.01);
+        assert!(!GenericTriangle::check_triangle_sides([1.0_f32, 2.0, 5.0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ssa_two_solutions() -> Result<(), String> {
+        let solutions = Triangle::new_ssa([None, Some(2.0), Some(1.5)], [None, None, Some(0.6)]);
+        assert_eq!(solutions.len(), 2);
+        for triangle in &solutions {
+            assert!((triangle.angle(2).unwrap() - 0.6).abs() < ROUNDING_ERROR);
+        }
+        assert!((solutions[0].side(0).unwrap() - 2.64).abs() < 0.01);
+        assert!((solutions[1].side(0).unwrap() - 0.66).abs() < 0.01);
+        assert!(Triangle::new([None, Some(2.0), Some(1.5)], [None, None, Some(0.6)]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ssa_single_solutions() -> Result<(), String> {
+        use core::f64::consts::PI;
+        //opposite side is tangent, so the angle in front of the adjacent side is right
+        let tangent = Triangle::new_ssa([None, Some(2.0), Some(1.0)], [None, None, Some(PI / 6.0)]);
+        assert_eq!(tangent.len(), 1);
+        assert!((tangent[0].angle(1).unwrap() - PI / 2.0).abs() < 0.0000001);
+        //opposite side longer than the adjacent one
+        let longer = Triangle::new([Some(3.0), Some(2.0), None], [Some(1.0), None, None]).unwrap();
+        assert!((longer.angle(0).unwrap() - 1.0).abs() < ROUNDING_ERROR);
+        //opposite side too short to reach the third side
+        assert!(
+            Triangle::new_ssa([None, Some(2.0), Some(0.5)], [None, None, Some(PI / 6.0)])
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ssa_isosceles() -> Result<(), String> {
+        use core::f64::consts::PI;
+        //opposite side equal to the adjacent one, the second root is exactly 0
+        //a right angle is the degenerate boundary between the two cases
+        for step in (1..1000).filter(|&step| step != 500) {
+            let angle = PI * step as f64 / 1000.0;
+            let solutions =
+                Triangle::new_ssa([None, Some(2.0), Some(2.0)], [None, None, Some(angle)]);
+            if angle < PI / 2.0 {
+                assert_eq!(solutions.len(), 1);
+                assert!((solutions[0].side(0).unwrap() - 4.0 * angle.cos()).abs() < ROUNDING_ERROR);
+                assert!(
+                    Triangle::new([None, Some(2.0), Some(2.0)], [None, None, Some(angle)])
+                        .is_some()
+                );
+            } else {
+                assert!(solutions.is_empty());
+            }
+        }
+        let triangle =
+            Triangle::new([None, Some(2.0), Some(2.0)], [None, None, Some(0.0195)]).unwrap();
+        assert!((triangle.angle(1).unwrap() - 0.0195).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
 }
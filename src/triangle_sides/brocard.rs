@@ -0,0 +1,93 @@
+use super::{Center, Float, GenericTriangle, Point, Slot};
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //cot(brocard angle) = cot(angle 0) + cot(angle 1) + cot(angle 2)
+    pub fn brocard_angle(&self) -> Option<T> {
+        let [Some(t0), Some(t1), Some(t2)] = self.tangents() else {
+            return None;
+        };
+        let one = T::one();
+        Some(
+            self.brocard_angle
+                .get_or_init(|| (one / (one / t0 + one / t1 + one / t2)).atan()),
+        )
+    }
+    pub fn brocard_angle_mut(&mut self) -> Option<T> {
+        self.brocard_angle()
+    }
+    //normalized barycentric coordinates of the first and the second Brocard point,
+    //the lines from the first one to the vertices make the Brocard angle with
+    //the sides 2, 0 and 1 (from vertex 0 to 1, 1 to 2 and 2 to 0)
+    pub fn brocard_points(&self) -> [[T; 3]; 2] {
+        let [q0, q1, q2] = self.sides.map(|side| T::one() / (side * side));
+        let normalize = |weights: [T; 3]| {
+            let sum = weights[0] + weights[1] + weights[2];
+            weights.map(|weight| weight / sum)
+        };
+        [normalize([q1, q2, q0]), normalize([q2, q0, q1])]
+    }
+    pub fn brocard_points_cartesian(&self) -> Option<[Point<T>; 2]> {
+        let placement = self.placement?;
+        Some(self.brocard_points().map(|point| placement.combine(point)))
+    }
+    //center and radius of the circle with diameter from the circumcenter to the
+    //symmedian point, both Brocard points lie on it
+    pub fn brocard_circle(&self) -> ([T; 3], T) {
+        let circumcenter = self.barycentric(Center::Circumcenter);
+        let squared = self.sides.map(|side| side * side);
+        let sum = squared[0] + squared[1] + squared[2];
+        let symmedian = squared.map(|square| square / sum);
+        let two = T::from_f64(2.0);
+        (
+            [0, 1, 2].map(|i| (circumcenter[i] + symmedian[i]) / two),
+            self.barycentric_distance(circumcenter, symmedian) / two,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::{Point, Triangle};
+
+    #[test]
+    fn test_brocard_angle() -> Result<(), String> {
+        let equilateral = Triangle::new_from_sides([2.0, 2.0, 2.0]).unwrap();
+        let omega = equilateral.brocard_angle().unwrap();
+        assert!((omega - std::f64::consts::FRAC_PI_6).abs() < ROUNDING_ERROR);
+        let mut triangle = Triangle::new_blank([6.0, 5.0, 4.0]).unwrap();
+        let [a, b, c] = [6.0, 5.0, 4.0_f64];
+        let area = triangle.area().unwrap();
+        //cot = (a^2 + b^2 + c^2) / (4 * area)
+        let expected = (4.0 * area / (a * a + b * b + c * c)).atan();
+        assert!((triangle.brocard_angle_mut().unwrap() - expected).abs() < ROUNDING_ERROR);
+        triangle.scale(3.0).unwrap();
+        assert!((triangle.brocard_angle().unwrap() - expected).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_brocard_points() -> Result<(), String> {
+        let vertices = [(0.0, 0.0), (6.0, 0.0), (1.0, 4.0)];
+        let triangle = Triangle::new_from_points(vertices).unwrap();
+        let omega = triangle.brocard_angle().unwrap();
+        let points = triangle.brocard_points_cartesian().unwrap();
+        let angle = |from: (f64, f64), to: (f64, f64), point: Point<f64>| {
+            let Point::Plane(x, y) = point else {
+                return f64::NAN;
+            };
+            let (u, v) = ((to.0 - from.0, to.1 - from.1), (x - from.0, y - from.1));
+            ((u.0 * v.0 + u.1 * v.1) / (u.0.hypot(u.1) * v.0.hypot(v.1))).acos()
+        };
+        for i in 0..3 {
+            let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+            assert!((angle(vertices[i], vertices[j], points[0]) - omega).abs() < ROUNDING_ERROR);
+            assert!((angle(vertices[i], vertices[k], points[1]) - omega).abs() < ROUNDING_ERROR);
+        }
+        let (center, radius) = triangle.brocard_circle();
+        for point in triangle.brocard_points() {
+            assert!((triangle.barycentric_distance(center, point) - radius).abs() < ROUNDING_ERROR);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Float, GenericTriangle, Point, Slot};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Center {
+    //intersection of the medians
+    Centroid,
+    //center of the incircle, intersection of the bisectors
+    Incenter,
+    //center of the circumcircle
+    Circumcenter,
+    //intersection of the heights
+    Orthocenter,
+}
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //normalized barycentric coordinates, weight i belongs to vertex i
+    pub fn barycentric(&self, center: Center) -> [T; 3] {
+        let squared = self.sides.map(|side| side * side);
+        //half of the law of cosines numerator, sides[i] * sides[j] * cos(angle k)
+        let [c0, c1, c2] = [0, 1, 2]
+            .map(|i| (squared[(i + 1) % 3] + squared[(i + 2) % 3] - squared[i]) / T::from_f64(2.0));
+        let weights = match center {
+            Center::Centroid => [T::one(); 3],
+            Center::Incenter => self.sides,
+            Center::Circumcenter => [squared[0] * c0, squared[1] * c1, squared[2] * c2],
+            //does not go through the tangents, so it works for right triangles
+            Center::Orthocenter => [c1 * c2, c2 * c0, c0 * c1],
+        };
+        Self::normalize(weights)
+    }
+    //needs the points of the vertices, see placement()
+    pub fn center_point(&self, center: Center) -> Option<Point<T>> {
+        let placement = self.placement?;
+        Some(placement.combine(self.barycentric(center)))
+    }
+    //distance between two points given in barycentric coordinates,
+    //they do not have to be normalized
+    pub fn barycentric_distance(&self, p: [T; 3], q: [T; 3]) -> T {
+        let [p, q] = [Self::normalize(p), Self::normalize(q)];
+        let d = [p[0] - q[0], p[1] - q[1], p[2] - q[2]];
+        let [s0, s1, s2] = self.sides;
+        let squared = -(s0 * s0 * d[1] * d[2] + s1 * s1 * d[2] * d[0] + s2 * s2 * d[0] * d[1]);
+        //rounding can make it slightly negative for the same points
+        squared.max(T::zero()).sqrt()
+    }
+    pub fn center_distance(&self, from: Center, to: Center) -> T {
+        self.barycentric_distance(self.barycentric(from), self.barycentric(to))
+    }
+    fn normalize(weights: [T; 3]) -> [T; 3] {
+        let sum = weights[0] + weights[1] + weights[2];
+        weights.map(|weight| weight / sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::{close, ROUNDING_ERROR};
+    use crate::triangle_sides::{Center, Triangle};
+
+    #[test]
+    fn test_center_points() -> Result<(), String> {
+        //right angle at vertex 0
+        let triangle = Triangle::new_from_points([(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]).unwrap();
+        assert!(close(
+            triangle.center_point(Center::Centroid),
+            4.0 / 3.0,
+            1.0
+        ));
+        assert!(close(triangle.center_point(Center::Incenter), 1.0, 1.0));
+        assert!(close(triangle.center_point(Center::Circumcenter), 2.0, 1.5));
+        assert!(close(triangle.center_point(Center::Orthocenter), 0.0, 0.0));
+        let blank = Triangle::new_blank([5.0, 3.0, 4.0]).unwrap();
+        assert_eq!(blank.center_point(Center::Centroid), None);
+        assert_eq!(blank.barycentric(Center::Orthocenter), [1.0, 0.0, 0.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_center_distances() -> Result<(), String> {
+        for sides in [[5.0, 3.0, 4.0], [7.0, 4.0, 5.0], [2.0, 3.0, 3.5]] {
+            let triangle = Triangle::new_from_sides(sides).unwrap();
+            let big_r = triangle.circumradius().unwrap();
+            let r = triangle.inradius().unwrap();
+            let squares = sides.iter().map(|side| side * side).sum::<f64>();
+            //Euler's theorem
+            let oi = triangle.center_distance(Center::Circumcenter, Center::Incenter);
+            assert!((oi * oi - big_r * (big_r - 2.0 * r)).abs() < ROUNDING_ERROR * 100.0);
+            let oh = triangle.center_distance(Center::Circumcenter, Center::Orthocenter);
+            assert!((oh * oh - (9.0 * big_r * big_r - squares)).abs() < ROUNDING_ERROR * 100.0);
+            //the centroid lies on the Euler line, a third of the way from O to H
+            let og = triangle.center_distance(Center::Circumcenter, Center::Centroid);
+            let gh = triangle.center_distance(Center::Centroid, Center::Orthocenter);
+            assert!((og * 3.0 - oh).abs() < ROUNDING_ERROR * 100.0);
+            assert!((og + gh - oh).abs() < ROUNDING_ERROR * 100.0);
+        }
+        Ok(())
+    }
+}
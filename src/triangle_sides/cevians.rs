@@ -0,0 +1,157 @@
+use super::{Float, GenericTriangle, Measurements, Quantity, TriangleError};
+
+impl<T: Float> GenericTriangle<T> {
+    pub fn new_from_medians(medians: [T; 3]) -> Option<GenericTriangle<T>> {
+        Self::try_new_from_medians(medians).ok()
+    }
+    //the medians make a triangle with 3/4 of the area, its medians are 3/4 of the sides
+    pub fn try_new_from_medians(medians: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::check_cevians(medians, Quantity::Median)?;
+        if !Self::non_degenerate(medians) {
+            return Err(TriangleError::MedianInequality { medians });
+        }
+        let squared = medians.map(|median| median * median);
+        let two = T::from_f64(2.0);
+        Self::try_new_from_sides([0, 1, 2].map(|i| {
+            let sum = two * (squared[(i + 1) % 3] + squared[(i + 2) % 3]) - squared[i];
+            two / T::from_f64(3.0) * sum.sqrt()
+        }))
+    }
+    pub fn new_from_heights(heights: [T; 3]) -> Option<GenericTriangle<T>> {
+        Self::try_new_from_heights(heights).ok()
+    }
+    //sides are proportional to the reciprocals of the heights
+    pub fn try_new_from_heights(heights: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::check_cevians(heights, Quantity::Height)?;
+        let reciprocals = heights.map(|height| T::one() / height);
+        if !Self::non_degenerate(reciprocals) {
+            return Err(TriangleError::HeightInequality { heights });
+        }
+        let double_area = T::from_f64(2.0) * GenericTriangle::area_calc(reciprocals);
+        Self::try_new_from_sides(reciprocals.map(|reciprocal| reciprocal / double_area))
+    }
+    pub fn new_from_bisectors(bisectors: [T; 3]) -> Option<GenericTriangle<T>> {
+        Self::try_new_from_bisectors(bisectors).ok()
+    }
+    //there is no closed formula, so the bisectors are given to the solver,
+    //which finds the only triangle that has them
+    pub fn try_new_from_bisectors(
+        bisectors: [T; 3],
+    ) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::check_cevians(bisectors, Quantity::Bisector)?;
+        let measurements = Measurements {
+            bisectors: bisectors.map(Some),
+            ..Default::default()
+        };
+        let tolerance = T::from_f64(0.0000000001).max(T::rounding_error());
+        let mut solutions = Self::try_solve(&measurements, tolerance)?;
+        Ok(solutions.swap_remove(0))
+    }
+    //strict triangle inequality, check_triangle_sides accepts degenerate triangles,
+    //which would have zero sides or divide by a zero area
+    fn non_degenerate(values: [T; 3]) -> bool {
+        let max = GenericTriangle::max_side(values);
+        max < values[0] + values[1] + values[2] - max
+    }
+    fn check_cevians(
+        values: [T; 3],
+        quantity: fn(usize) -> Quantity,
+    ) -> Result<(), TriangleError<T>> {
+        for (index, &value) in values.iter().enumerate() {
+            if value <= T::zero() {
+                return Err(TriangleError::InvalidMeasurement {
+                    quantity: quantity(index),
+                    value,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::{Quantity, Triangle, TriangleError};
+
+    fn round_trip(values: [Option<f64>; 3], result: [Option<f64>; 3], tolerance: f64) -> bool {
+        (0..3).all(|i| (values[i].unwrap() - result[i].unwrap()).abs() < tolerance)
+    }
+
+    #[test]
+    fn test_from_cevians() -> Result<(), String> {
+        for sides in [[5.0, 3.0, 4.0], [6.0, 5.0, 4.0], [2.0, 2.0, 3.9]] {
+            let triangle = Triangle::new_from_sides(sides).unwrap();
+            let medians = triangle.medians().map(Option::unwrap);
+            let from_medians = Triangle::new_from_medians(medians).unwrap();
+            assert!(round_trip(
+                triangle.sides(),
+                from_medians.sides(),
+                ROUNDING_ERROR * 10.0
+            ));
+            assert!(round_trip(
+                triangle.medians(),
+                from_medians.medians(),
+                ROUNDING_ERROR * 10.0
+            ));
+            let heights = triangle.heights().map(Option::unwrap);
+            let from_heights = Triangle::new_from_heights(heights).unwrap();
+            assert!(round_trip(
+                triangle.sides(),
+                from_heights.sides(),
+                ROUNDING_ERROR * 100.0
+            ));
+            assert!(round_trip(
+                triangle.heights(),
+                from_heights.heights(),
+                ROUNDING_ERROR * 100.0
+            ));
+            let bisectors = triangle.bisectors().map(Option::unwrap);
+            let from_bisectors = Triangle::new_from_bisectors(bisectors).unwrap();
+            assert!(round_trip(
+                triangle.sides(),
+                from_bisectors.sides(),
+                0.000000001
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_infeasible_cevians() -> Result<(), String> {
+        assert_eq!(
+            Triangle::try_new_from_medians([1.0, 1.0, 5.0]).unwrap_err(),
+            TriangleError::MedianInequality {
+                medians: [1.0, 1.0, 5.0]
+            }
+        );
+        //reciprocals 1, 1 and 2.5 are not a triangle
+        assert_eq!(
+            Triangle::try_new_from_heights([1.0, 1.0, 0.4]).unwrap_err(),
+            TriangleError::HeightInequality {
+                heights: [1.0, 1.0, 0.4]
+            }
+        );
+        //degenerate medians and heights
+        assert_eq!(
+            Triangle::try_new_from_medians([1.0, 1.0, 2.0]).unwrap_err(),
+            TriangleError::MedianInequality {
+                medians: [1.0, 1.0, 2.0]
+            }
+        );
+        assert_eq!(
+            Triangle::try_new_from_heights([1.0, 1.0, 0.5]).unwrap_err(),
+            TriangleError::HeightInequality {
+                heights: [1.0, 1.0, 0.5]
+            }
+        );
+        assert_eq!(
+            Triangle::try_new_from_bisectors([1.0, 0.0, 1.0]).unwrap_err(),
+            TriangleError::InvalidMeasurement {
+                quantity: Quantity::Bisector(1),
+                value: 0.0
+            }
+        );
+        Ok(())
+    }
+}
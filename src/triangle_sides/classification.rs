@@ -0,0 +1,133 @@
+use super::{Float, GenericTriangle, Slot};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SideClass {
+    Equilateral,
+    Isosceles,
+    Scalene,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AngleClass {
+    Acute,
+    Right,
+    Obtuse,
+    //the longest side is as long as the other two together
+    Degenerate,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Classification {
+    pub sides: SideClass,
+    pub angles: AngleClass,
+}
+
+//tolerance in all functions below is relative to the longest side,
+//use Float::rounding_error() if you only want to cover rounding
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    pub fn classify(&self, tolerance: T) -> Classification {
+        Classification {
+            sides: self.side_class(tolerance),
+            angles: self.angle_class(tolerance),
+        }
+    }
+    pub fn side_class(&self, tolerance: T) -> SideClass {
+        let [s0, s1, s2] = self.sides;
+        let margin = tolerance * GenericTriangle::max_side(self.sides);
+        let equal = |x: T, y: T| (x - y).abs() <= margin;
+        match (equal(s0, s1), equal(s1, s2), equal(s2, s0)) {
+            (true, true, _) | (true, _, true) | (_, true, true) => SideClass::Equilateral,
+            (false, false, false) => SideClass::Scalene,
+            _ => SideClass::Isosceles,
+        }
+    }
+    pub fn angle_class(&self, tolerance: T) -> AngleClass {
+        let mut sorted = self.sides;
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        let [a, b, c] = sorted;
+        if a + b - c <= tolerance * c {
+            return AngleClass::Degenerate;
+        }
+        //law of cosines for the biggest angle
+        let difference = a * a + b * b - c * c;
+        if difference.abs() <= tolerance * c * c {
+            AngleClass::Right
+        } else if difference > T::zero() {
+            AngleClass::Acute
+        } else {
+            AngleClass::Obtuse
+        }
+    }
+    pub fn is_equilateral(&self, tolerance: T) -> bool {
+        self.side_class(tolerance) == SideClass::Equilateral
+    }
+    //equilateral triangles are isosceles too
+    pub fn is_isosceles(&self, tolerance: T) -> bool {
+        self.side_class(tolerance) != SideClass::Scalene
+    }
+    pub fn is_scalene(&self, tolerance: T) -> bool {
+        self.side_class(tolerance) == SideClass::Scalene
+    }
+    pub fn is_acute(&self, tolerance: T) -> bool {
+        self.angle_class(tolerance) == AngleClass::Acute
+    }
+    pub fn is_right(&self, tolerance: T) -> bool {
+        self.angle_class(tolerance) == AngleClass::Right
+    }
+    pub fn is_obtuse(&self, tolerance: T) -> bool {
+        self.angle_class(tolerance) == AngleClass::Obtuse
+    }
+    pub fn is_degenerate(&self, tolerance: T) -> bool {
+        self.angle_class(tolerance) == AngleClass::Degenerate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::{AngleClass, Classification, SideClass, Triangle};
+
+    #[test]
+    fn test_classify() -> Result<(), String> {
+        let egyptian = Triangle::new_from_sides([3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(
+            egyptian.classify(ROUNDING_ERROR),
+            Classification {
+                sides: SideClass::Scalene,
+                angles: AngleClass::Right
+            }
+        );
+        let equilateral = Triangle::new_from_sides([2.0, 2.0, 2.0]).unwrap();
+        assert!(equilateral.is_equilateral(ROUNDING_ERROR));
+        assert!(equilateral.is_isosceles(ROUNDING_ERROR));
+        assert!(equilateral.is_acute(ROUNDING_ERROR));
+        let obtuse = Triangle::new_from_sides([2.0, 2.0, 3.5]).unwrap();
+        assert_eq!(obtuse.side_class(ROUNDING_ERROR), SideClass::Isosceles);
+        assert!(obtuse.is_obtuse(ROUNDING_ERROR));
+        let flat = Triangle::new_from_sides([1.0, 2.0, 3.0]).unwrap();
+        assert!(flat.is_degenerate(ROUNDING_ERROR));
+        Ok(())
+    }
+
+    #[test]
+    fn test_classify_tolerance() -> Result<(), String> {
+        use core::f64::consts::PI;
+        //angles are converted to sides with rounding errors
+        let right = Triangle::new(
+            [Some(1.0), None, None],
+            [Some(PI / 6.0), Some(PI / 3.0), None],
+        )
+        .unwrap();
+        assert!(right.is_right(ROUNDING_ERROR));
+        let equilateral = Triangle::new(
+            [Some(1.0), None, None],
+            [Some(PI / 3.0), Some(PI / 3.0), None],
+        )
+        .unwrap();
+        assert!(equilateral.is_equilateral(ROUNDING_ERROR));
+        let almost = Triangle::new_from_sides([1.0, 1.0001, 0.9999]).unwrap();
+        assert!(almost.is_scalene(ROUNDING_ERROR));
+        assert!(almost.is_equilateral(0.001));
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use super::{Float, GenericTriangle, Placement, Point, Slot};
+
+//where a point is relative to the triangle, indices are the same as for the sides
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    OnEdge(usize),
+    AtVertex(usize),
+    Outside,
+}
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //None if the coordinates sum to zero, such point is at infinity
+    pub fn normalized_barycentric(&self, barycentric: [T; 3]) -> Option<[T; 3]> {
+        let sum = barycentric[0] + barycentric[1] + barycentric[2];
+        match sum.abs().partial_cmp(&T::zero()) {
+            Some(std::cmp::Ordering::Greater) => Some(barycentric.map(|weight| weight / sum)),
+            _ => None,
+        }
+    }
+    pub fn barycentric_from_trilinear(&self, trilinear: [T; 3]) -> Option<[T; 3]> {
+        let [s0, s1, s2] = self.sides;
+        self.normalized_barycentric([s0 * trilinear[0], s1 * trilinear[1], s2 * trilinear[2]])
+    }
+    //exact trilinear coordinates, the signed distances of the point to the sides,
+    //negative on the other side than the triangle
+    pub fn trilinear_from_barycentric(&self, barycentric: [T; 3]) -> Option<[T; 3]> {
+        let normalized = self.normalized_barycentric(barycentric)?;
+        let double_area = self.area()? * T::from_f64(2.0);
+        Some([0, 1, 2].map(|i| double_area * normalized[i] / self.sides[i]))
+    }
+    //needs the points of the vertices, see placement()
+    pub fn cartesian_from_barycentric(&self, barycentric: [T; 3]) -> Option<Point<T>> {
+        let placement = self.placement?;
+        Some(placement.combine(self.normalized_barycentric(barycentric)?))
+    }
+    //points in the space are projected to the plane of the triangle
+    pub fn barycentric_from_cartesian(&self, point: Point<T>) -> Option<[T; 3]> {
+        let vertices = match self.placement? {
+            Placement::Plane(v) => v.map(|(x, y)| [x, y, T::zero()]),
+            Placement::Space(v) => v.map(|(x, y, z)| [x, y, z]),
+        };
+        let point = match point {
+            Point::Plane(x, y) => [x, y, T::zero()],
+            Point::Space(x, y, z) => [x, y, z],
+        };
+        let from_0 = |p: [T; 3]| [0, 1, 2].map(|k| p[k] - vertices[0][k]);
+        let dot = |p: [T; 3], q: [T; 3]| p[0] * q[0] + p[1] * q[1] + p[2] * q[2];
+        let (e1, e2, e) = (from_0(vertices[1]), from_0(vertices[2]), from_0(point));
+        let (d11, d12, d22) = (dot(e1, e1), dot(e1, e2), dot(e2, e2));
+        let (d1, d2) = (dot(e, e1), dot(e, e2));
+        let denominator = d11 * d22 - d12 * d12;
+        let w1 = (d22 * d1 - d12 * d2) / denominator;
+        let w2 = (d11 * d2 - d12 * d1) / denominator;
+        Some([T::one() - w1 - w2, w1, w2])
+    }
+    //tolerance is relative to the longest side, like in classify()
+    pub fn locate(&self, barycentric: [T; 3], tolerance: T) -> Option<Location> {
+        let distances = self.trilinear_from_barycentric(barycentric)?;
+        let margin = tolerance * GenericTriangle::max_side(self.sides);
+        if distances.iter().any(|&distance| distance < -margin) {
+            return Some(Location::Outside);
+        }
+        let near = distances.map(|distance| distance.abs() <= margin);
+        Some(match near {
+            [false, false, false] => Location::Inside,
+            [true, false, false] => Location::OnEdge(0),
+            [false, true, false] => Location::OnEdge(1),
+            [false, false, true] => Location::OnEdge(2),
+            [false, true, true] => Location::AtVertex(0),
+            [true, false, true] => Location::AtVertex(1),
+            _ => Location::AtVertex(2),
+        })
+    }
+    pub fn locate_point(&self, point: Point<T>, tolerance: T) -> Option<Location> {
+        self.locate(self.barycentric_from_cartesian(point)?, tolerance)
+    }
+    //signed distances of the point to the lines of the sides
+    pub fn side_distances(&self, point: Point<T>) -> Option<[T; 3]> {
+        self.trilinear_from_barycentric(self.barycentric_from_cartesian(point)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::{same, ROUNDING_ERROR};
+    use crate::triangle_sides::{Location, Point, Triangle};
+
+    #[test]
+    fn test_conversions() -> Result<(), String> {
+        let triangle = Triangle::new_from_points([(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]).unwrap();
+        let incenter = triangle
+            .barycentric_from_cartesian(Point::Plane(1.0, 1.0))
+            .unwrap();
+        assert!(same(incenter, [5.0 / 12.0, 0.25, 1.0 / 3.0]));
+        assert!(same(
+            triangle.trilinear_from_barycentric(incenter).unwrap(),
+            [1.0; 3]
+        ));
+        assert!(same(
+            triangle
+                .barycentric_from_trilinear([2.0, 2.0, 2.0])
+                .unwrap(),
+            incenter
+        ));
+        assert!(same(
+            triangle.normalized_barycentric([2.0, 1.0, 1.0]).unwrap(),
+            [0.5, 0.25, 0.25]
+        ));
+        assert_eq!(triangle.normalized_barycentric([1.0, -1.0, 0.0]), None);
+        assert_eq!(
+            triangle.cartesian_from_barycentric([0.0, 1.0, 1.0]),
+            Some(Point::Plane(2.0, 1.5))
+        );
+        let distances = triangle.side_distances(Point::Plane(2.0, -1.0)).unwrap();
+        assert!(same(distances, [2.0, 2.0, -1.0]));
+        let space =
+            Triangle::new_from_points_3d([(0.0, 0.0, 1.0), (4.0, 0.0, 1.0), (0.0, 3.0, 1.0)])
+                .unwrap();
+        let above = space.barycentric_from_cartesian(Point::Space(1.0, 1.0, 5.0));
+        assert!(same(above.unwrap(), incenter));
+        assert_eq!(
+            Triangle::new_blank([5.0, 3.0, 4.0])
+                .unwrap()
+                .barycentric_from_cartesian(Point::Plane(1.0, 1.0)),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_locate() -> Result<(), String> {
+        let triangle = Triangle::new_from_points([(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]).unwrap();
+        let locate = |x, y| triangle.locate_point(Point::Plane(x, y), ROUNDING_ERROR);
+        assert_eq!(locate(1.0, 1.0), Some(Location::Inside));
+        assert_eq!(locate(2.0, 1.5), Some(Location::OnEdge(0)));
+        assert_eq!(locate(0.0, 1.0), Some(Location::OnEdge(1)));
+        assert_eq!(locate(4.0, 0.0), Some(Location::AtVertex(1)));
+        assert_eq!(locate(0.0, 0.0), Some(Location::AtVertex(0)));
+        assert_eq!(locate(3.0, 3.0), Some(Location::Outside));
+        assert_eq!(
+            triangle.locate_point(Point::Plane(2.0, 0.001), 0.001),
+            Some(Location::OnEdge(2))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use super::{Float, GenericTriangle, Slot, TriangleError};
+
+//vertex i of every derived triangle belongs to the side or the vertex i of the
+//original one, so side i of the derived triangle is opposite to it, the
+//accessors of all three values return None only for invalid indices
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //vertices in the middle of the sides
+    pub fn medial(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::derived(self.sides.map(|side| side / T::from_f64(2.0)))
+    }
+    //the triangle this one is medial to
+    pub fn anticomplementary(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::derived(self.sides.map(|side| side * T::from_f64(2.0)))
+    }
+    //vertices at the feet of the heights, degenerate for right triangles
+    pub fn orthic(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let cosines = self.cosines().map(Option::unwrap);
+        Self::derived([0, 1, 2].map(|i| self.sides[i] * cosines[i].abs()))
+    }
+    //vertices where the incircle touches the sides
+    pub fn intouch(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::derived(self.contact_sides().map(Option::unwrap))
+    }
+    //vertices at the centers of the excircles
+    pub fn excentral(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let big_r = self.circumradius().unwrap();
+        let angles = self.angles().map(Option::unwrap);
+        let two = T::from_f64(2.0);
+        Self::derived(angles.map(|angle| two * two * big_r * (angle / two).cos()))
+    }
+    //sides tangent to the circumcircle at the vertices, undefined for right triangles
+    pub fn tangential(&self) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let cosines = self.cosines().map(Option::unwrap);
+        let products = [0, 1, 2].map(|i| (cosines[(i + 1) % 3] * cosines[(i + 2) % 3]).abs());
+        if products
+            .iter()
+            .any(|&product| product <= T::rounding_error())
+        {
+            return Err(TriangleError::Degenerate { sides: self.sides });
+        }
+        Self::derived([0, 1, 2].map(|i| self.sides[i] / (T::from_f64(2.0) * products[i])))
+    }
+    //vertices at the feet of the perpendiculars from the point to the sides,
+    //degenerate for points on the circumcircle
+    pub fn pedal(&self, barycentric: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let sines = self.sines().map(Option::unwrap);
+        let vertices = [
+            [T::one(), T::zero(), T::zero()],
+            [T::zero(), T::one(), T::zero()],
+            [T::zero(), T::zero(), T::one()],
+        ];
+        //the feet on the sides next to vertex i lie on the circle with diameter from
+        //vertex i to the point
+        Self::derived(
+            [0, 1, 2].map(|i| self.barycentric_distance(vertices[i], barycentric) * sines[i]),
+        )
+    }
+    fn derived(sides: [T; 3]) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let mut sorted = sides;
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        let [a, b, c] = sorted;
+        //also catches NaN and infinite sides
+        match (a + b - c).partial_cmp(&(T::rounding_error() * c)) {
+            Some(std::cmp::Ordering::Greater) => GenericTriangle::try_new_from_sides(sides),
+            _ => Err(TriangleError::Degenerate { sides }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::same_sides;
+    use crate::triangle_sides::{Center, Triangle, TriangleError};
+
+    #[test]
+    fn test_derived() -> Result<(), String> {
+        let sides = [6.0, 5.0, 4.0];
+        let triangle = Triangle::new_from_sides(sides).unwrap();
+        assert!(same_sides(triangle.medial().unwrap(), [3.0, 2.5, 2.0]));
+        assert!(same_sides(
+            triangle.anticomplementary().unwrap(),
+            [12.0, 10.0, 8.0]
+        ));
+        //the original triangle is the orthic triangle of its excentral triangle
+        //and the intouch triangle of its tangential triangle
+        assert!(same_sides(
+            triangle.excentral().unwrap().orthic().unwrap(),
+            sides
+        ));
+        assert!(same_sides(
+            triangle.tangential().unwrap().intouch().unwrap(),
+            sides
+        ));
+        //pedal triangles of the classical centers
+        let pedal = |center| triangle.pedal(triangle.barycentric(center)).unwrap();
+        let medial = triangle.medial().unwrap().sides().map(Option::unwrap);
+        assert!(same_sides(pedal(Center::Circumcenter), medial));
+        let orthic = triangle.orthic().unwrap().sides().map(Option::unwrap);
+        assert!(same_sides(pedal(Center::Orthocenter), orthic));
+        let intouch = triangle.intouch().unwrap().sides().map(Option::unwrap);
+        assert!(same_sides(pedal(Center::Incenter), intouch));
+        Ok(())
+    }
+
+    #[test]
+    fn test_degenerate() -> Result<(), String> {
+        let right = Triangle::new_from_sides([5.0, 3.0, 4.0]).unwrap();
+        assert!(matches!(
+            right.orthic(),
+            Err(TriangleError::Degenerate { .. })
+        ));
+        assert!(right.tangential().is_err());
+        //Simson line of a vertex
+        assert!(right.pedal([0.0, 1.0, 0.0]).is_err());
+        let mut triangle = Triangle::new_from_sides([6.0, 5.0, 4.0]).unwrap();
+        for _ in 0..5 {
+            triangle = triangle.orthic().unwrap();
+        }
+        assert!(triangle.area().unwrap() > 0.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use super::{AngleClass, ExactTriangle, SideClass, Triangle};
+
+//every iterator yields the sides from the shortest one, [a, b, c] with a <= b <= c,
+//so each triangle is given once
+
+//integer triangles with the given perimeter
+#[derive(Clone, Debug)]
+pub struct PerimeterTriangles {
+    perimeter: u64,
+    a: u64,
+    b: u64,
+}
+
+impl PerimeterTriangles {
+    pub fn new(perimeter: u64) -> PerimeterTriangles {
+        PerimeterTriangles {
+            perimeter,
+            a: 1,
+            b: 1,
+        }
+    }
+}
+
+impl Iterator for PerimeterTriangles {
+    type Item = [u64; 3];
+    fn next(&mut self) -> Option<[u64; 3]> {
+        let p = self.perimeter;
+        while 3 * self.a <= p {
+            //a + b > c means 2 * (a + b) > p, c >= b means 2 * b <= p - a
+            self.b = self.b.max(self.a).max((p / 2 + 1).saturating_sub(self.a));
+            if 2 * self.b <= p - self.a {
+                let sides = [self.a, self.b, p - self.a - self.b];
+                self.b += 1;
+                return Some(sides);
+            }
+            self.a += 1;
+            self.b = 1;
+        }
+        None
+    }
+}
+
+//integer triangles with integer area and sides up to the bound
+#[derive(Clone, Debug)]
+pub struct HeronianTriangles {
+    max_side: u64,
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl HeronianTriangles {
+    pub fn new(max_side: u64) -> HeronianTriangles {
+        HeronianTriangles {
+            max_side,
+            a: 0,
+            b: 1,
+            c: 1,
+        }
+    }
+    //16 * area^2 has to be a square of a multiple of 4
+    fn is_heronian(a: u64, b: u64, c: u64) -> bool {
+        let (a, b, c) = (a as u128, b as u128, c as u128);
+        let product = (a + b + c) * (b + c - a) * (a + c - b) * (a + b - c);
+        let root = product.isqrt();
+        root * root == product && root % 4 == 0
+    }
+}
+
+impl Iterator for HeronianTriangles {
+    type Item = [u64; 3];
+    fn next(&mut self) -> Option<[u64; 3]> {
+        while self.c <= self.max_side {
+            self.a += 1;
+            //a + b > c
+            self.a = self.a.max(self.c - self.b + 1);
+            if self.a > self.b {
+                self.b += 1;
+                self.a = 0;
+                if self.b > self.c {
+                    self.c += 1;
+                    self.b = 1;
+                }
+                continue;
+            }
+            if HeronianTriangles::is_heronian(self.a, self.b, self.c) {
+                return Some([self.a, self.b, self.c]);
+            }
+        }
+        None
+    }
+}
+
+//primitive Pythagorean triples with the hypotenuse up to the bound, from
+//Euclid's formula m^2 - n^2, 2mn, m^2 + n^2 for coprime m > n of different parity
+#[derive(Clone, Debug)]
+pub struct PythagoreanTriples {
+    max_hypotenuse: u64,
+    m: u64,
+    n: u64,
+}
+
+impl PythagoreanTriples {
+    pub fn new(max_hypotenuse: u64) -> PythagoreanTriples {
+        PythagoreanTriples {
+            max_hypotenuse,
+            m: 2,
+            n: 0,
+        }
+    }
+}
+
+impl Iterator for PythagoreanTriples {
+    type Item = [u64; 3];
+    fn next(&mut self) -> Option<[u64; 3]> {
+        let max = self.max_hypotenuse as u128;
+        loop {
+            let (m, n) = (self.m as u128, self.n as u128 + 1);
+            //the smallest hypotenuse for this m is m^2 + 1
+            if m * m + 1 > max {
+                return None;
+            }
+            if n >= m || m * m + n * n > max {
+                self.m += 1;
+                self.n = 0;
+                continue;
+            }
+            self.n += 1;
+            if (m - n) % 2 == 1 && gcd(self.m, self.n) == 1 {
+                let legs = [(m * m - n * n) as u64, (2 * m * n) as u64];
+                return Some([
+                    legs[0].min(legs[1]),
+                    legs[0].max(legs[1]),
+                    (m * m + n * n) as u64,
+                ]);
+            }
+        }
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+//filters and conversion for every iterator of sides
+pub trait SidesIterator: Iterator<Item = [u64; 3]> + Sized {
+    //sides without a common divisor
+    fn primitive(self) -> impl Iterator<Item = [u64; 3]> {
+        self.filter(|&[a, b, c]| gcd(gcd(a, b), c) == 1)
+    }
+    fn with_side_class(self, class: SideClass) -> impl Iterator<Item = [u64; 3]> {
+        self.filter(move |&sides| {
+            ExactTriangle::new_from_integers(sides).is_some_and(|t| t.side_class() == class)
+        })
+    }
+    fn with_angle_class(self, class: AngleClass) -> impl Iterator<Item = [u64; 3]> {
+        self.filter(move |&sides| {
+            ExactTriangle::new_from_integers(sides).is_some_and(|t| t.angle_class() == class)
+        })
+    }
+    fn triangles(self) -> impl Iterator<Item = Triangle> {
+        self.filter_map(|sides| Triangle::new_from_sides(sides.map(|side| side as f64)))
+    }
+}
+
+impl<I: Iterator<Item = [u64; 3]>> SidesIterator for I {}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::{
+        AngleClass, ExactTriangle, HeronianTriangles, PerimeterTriangles, PythagoreanTriples,
+        SideClass, SidesIterator,
+    };
+
+    #[test]
+    fn test_perimeter_triangles() -> Result<(), String> {
+        let twelve: Vec<[u64; 3]> = PerimeterTriangles::new(12).collect();
+        assert_eq!(twelve, vec![[2, 5, 5], [3, 4, 5], [4, 4, 4]]);
+        //Alcuin's sequence
+        let counts: Vec<usize> = (1..=12)
+            .map(|p| PerimeterTriangles::new(p).count())
+            .collect();
+        assert_eq!(counts, vec![0, 0, 1, 0, 1, 1, 2, 1, 3, 2, 4, 3]);
+        let right: Vec<[u64; 3]> = PerimeterTriangles::new(24)
+            .with_angle_class(AngleClass::Right)
+            .collect();
+        assert_eq!(right, vec![[6, 8, 10]]);
+        assert_eq!(
+            PerimeterTriangles::new(24)
+                .primitive()
+                .with_angle_class(AngleClass::Right)
+                .count(),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_heronian_triangles() -> Result<(), String> {
+        let heronian: Vec<[u64; 3]> = HeronianTriangles::new(15).collect();
+        let mut expected = Vec::new();
+        for c in 1..=15 {
+            for b in 1..=c {
+                for a in 1..=b {
+                    if ExactTriangle::new_from_integers([a, b, c]).is_some_and(|t| t.is_heronian())
+                    {
+                        expected.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        assert_eq!(heronian, expected);
+        assert!(heronian.contains(&[13, 14, 15]) && heronian.contains(&[5, 5, 6]));
+        let isosceles = HeronianTriangles::new(10).with_side_class(SideClass::Isosceles);
+        assert_eq!(isosceles.collect::<Vec<_>>(), vec![[5, 5, 6], [5, 5, 8]]);
+        for triangle in HeronianTriangles::new(20).triangles() {
+            let area = triangle.area().unwrap();
+            assert!((area - area.round()).abs() < 0.000000001);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pythagorean_triples() -> Result<(), String> {
+        let triples: Vec<[u64; 3]> = PythagoreanTriples::new(30).collect();
+        assert_eq!(
+            triples,
+            vec![
+                [3, 4, 5],
+                [5, 12, 13],
+                [8, 15, 17],
+                [7, 24, 25],
+                [20, 21, 29]
+            ]
+        );
+        assert_eq!(
+            PythagoreanTriples::new(1000).primitive().count(),
+            PythagoreanTriples::new(1000).count()
+        );
+        assert!(PythagoreanTriples::new(1000).all(|[a, b, c]| a * a + b * b == c * c));
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use super::{Center, Float, GenericTriangle, Point, Slot};
+
+//points of the Euler line, ratio() is their position from the circumcenter (0)
+//to the orthocenter (1)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EulerPoint {
+    Circumcenter,
+    Centroid,
+    NinePointCenter,
+    Orthocenter,
+    DeLongchamps,
+}
+
+impl EulerPoint {
+    pub fn ratio<T: Float>(self) -> T {
+        match self {
+            EulerPoint::Circumcenter => T::zero(),
+            EulerPoint::Centroid => T::one() / T::from_f64(3.0),
+            EulerPoint::NinePointCenter => T::one() / T::from_f64(2.0),
+            EulerPoint::Orthocenter => T::one(),
+            //orthocenter reflected in the circumcenter
+            EulerPoint::DeLongchamps => -T::one(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EulerLine<T> {
+    //normalized barycentric coordinates
+    pub circumcenter: [T; 3],
+    pub orthocenter: [T; 3],
+    //distance between them
+    pub length: T,
+}
+
+impl<T: Float> EulerLine<T> {
+    //barycentric vector from the circumcenter to the orthocenter, it sums to zero
+    pub fn direction(&self) -> [T; 3] {
+        [0, 1, 2].map(|i| self.orthocenter[i] - self.circumcenter[i])
+    }
+    pub fn point_at(&self, ratio: T) -> [T; 3] {
+        let direction = self.direction();
+        [0, 1, 2].map(|i| self.circumcenter[i] + ratio * direction[i])
+    }
+    pub fn point(&self, point: EulerPoint) -> [T; 3] {
+        self.point_at(point.ratio())
+    }
+}
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    pub fn nine_point_radius(&self) -> Option<T> {
+        Some(self.circumradius()? / T::from_f64(2.0))
+    }
+    //halfway between the circumcenter and the orthocenter, also for equilateral triangles
+    pub fn nine_point_center(&self) -> [T; 3] {
+        let circumcenter = self.barycentric(Center::Circumcenter);
+        let orthocenter = self.barycentric(Center::Orthocenter);
+        [0, 1, 2].map(|i| (circumcenter[i] + orthocenter[i]) / T::from_f64(2.0))
+    }
+    pub fn nine_point_center_point(&self) -> Option<Point<T>> {
+        Some(self.placement?.combine(self.nine_point_center()))
+    }
+    //None for equilateral triangles, where all the centers are in the same place
+    pub fn euler_line(&self) -> Option<EulerLine<T>> {
+        let length = self.center_distance(Center::Circumcenter, Center::Orthocenter);
+        if length <= T::rounding_error() * GenericTriangle::max_side(self.sides) {
+            return None;
+        }
+        Some(EulerLine {
+            circumcenter: self.barycentric(Center::Circumcenter),
+            orthocenter: self.barycentric(Center::Orthocenter),
+            length,
+        })
+    }
+    //unit vector from the circumcenter to the orthocenter, needs the placement
+    pub fn euler_direction(&self) -> Option<Point<T>> {
+        let line = self.euler_line()?;
+        let placement = self.placement?;
+        let length = line.length;
+        Some(
+            match (
+                placement.combine(line.circumcenter),
+                placement.combine(line.orthocenter),
+            ) {
+                (Point::Plane(x0, y0), Point::Plane(x1, y1)) => {
+                    Point::Plane((x1 - x0) / length, (y1 - y0) / length)
+                }
+                (Point::Space(x0, y0, z0), Point::Space(x1, y1, z1)) => {
+                    Point::Space((x1 - x0) / length, (y1 - y0) / length, (z1 - z0) / length)
+                }
+                _ => return None,
+            },
+        )
+    }
+    //where the incircle touches the nine-point circle, None for equilateral triangles,
+    //where the two circles are the same
+    pub fn feuerbach_point(&self) -> Option<[T; 3]> {
+        let [s0, s1, s2] = self.sides;
+        let weight = |a: T, b: T, c: T| (b - c) * (b - c) * (b + c - a);
+        let weights = [weight(s0, s1, s2), weight(s1, s2, s0), weight(s2, s0, s1)];
+        let sum = weights[0] + weights[1] + weights[2];
+        let max = GenericTriangle::max_side(self.sides);
+        if sum <= T::rounding_error() * max * max * max {
+            return None;
+        }
+        Some(weights.map(|weight| weight / sum))
+    }
+    pub fn feuerbach_point_cartesian(&self) -> Option<Point<T>> {
+        Some(self.placement?.combine(self.feuerbach_point()?))
+    }
+    //OH^2 = 9R^2 - (a^2 + b^2 + c^2), OG = OH / 3, ON = OH / 2 and
+    //IN = R / 2 - r (Feuerbach's theorem)
+    pub fn check_euler(&self, tolerance: T) -> bool {
+        let (Some(big_r), Some(r)) = (self.circumradius(), self.inradius()) else {
+            return false;
+        };
+        let [s0, s1, s2] = self.sides;
+        let margin = tolerance * big_r;
+        let oh = self.center_distance(Center::Circumcenter, Center::Orthocenter);
+        let og = self.center_distance(Center::Circumcenter, Center::Centroid);
+        let circumcenter = self.barycentric(Center::Circumcenter);
+        let nine_point = self.nine_point_center();
+        let on = self.barycentric_distance(circumcenter, nine_point);
+        let incenter = self.barycentric(Center::Incenter);
+        let inn = self.barycentric_distance(incenter, nine_point);
+        let nine = T::from_f64(9.0);
+        (oh * oh - (nine * big_r * big_r - s0 * s0 - s1 * s1 - s2 * s2)).abs() <= margin * big_r
+            && (og * T::from_f64(3.0) - oh).abs() <= margin
+            && (on * T::from_f64(2.0) - oh).abs() <= margin
+            && (inn - (big_r / T::from_f64(2.0) - r)).abs() <= margin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::{close, same, ROUNDING_ERROR};
+    use crate::triangle_sides::{Center, EulerPoint, Triangle};
+
+    #[test]
+    fn test_euler_line() -> Result<(), String> {
+        let triangle = Triangle::new_from_points([(0.0, 0.0), (4.0, 0.0), (1.0, 3.0)]).unwrap();
+        assert!(triangle.check_euler(ROUNDING_ERROR * 10.0));
+        let line = triangle.euler_line().unwrap();
+        assert!(same(
+            line.point(EulerPoint::Centroid),
+            triangle.barycentric(Center::Centroid)
+        ));
+        assert!(same(
+            line.point(EulerPoint::NinePointCenter),
+            triangle.nine_point_center()
+        ));
+        assert!(line.direction().iter().sum::<f64>().abs() < ROUNDING_ERROR);
+        //circumcenter (2, 1), orthocenter (1, 1)
+        assert!(close(triangle.euler_direction(), -1.0, 0.0));
+        assert!(close(triangle.nine_point_center_point(), 1.5, 1.0));
+        assert!(
+            (triangle.nine_point_radius().unwrap() * 2.0 - 5.0_f64.sqrt()).abs() < ROUNDING_ERROR
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_feuerbach_point() -> Result<(), String> {
+        let triangle = Triangle::new_from_sides([6.0, 5.0, 4.0]).unwrap();
+        let feuerbach = triangle.feuerbach_point().unwrap();
+        let incenter = triangle.barycentric(Center::Incenter);
+        let nine_point = triangle.nine_point_center();
+        //the point is on both circles
+        let r = triangle.inradius().unwrap();
+        let radius = triangle.nine_point_radius().unwrap();
+        assert!((triangle.barycentric_distance(feuerbach, incenter) - r).abs() < ROUNDING_ERROR);
+        assert!(
+            (triangle.barycentric_distance(feuerbach, nine_point) - radius).abs() < ROUNDING_ERROR
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_equilateral() -> Result<(), String> {
+        let triangle = Triangle::new_from_sides([2.0, 2.0, 2.0]).unwrap();
+        assert!(triangle.euler_line().is_none());
+        assert!(triangle.feuerbach_point().is_none());
+        assert!(triangle.check_euler(ROUNDING_ERROR * 10.0));
+        assert!(same(triangle.nine_point_center(), [1.0 / 3.0; 3]));
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use super::{AngleClass, Rational, SideClass, Triangle, TriangleError};
+
+//triangle with rational sides, the quantities below are rational in the
+//squared sides and are calculated without rounding
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExactTriangle {
+    sides: [Rational; 3],
+}
+
+impl ExactTriangle {
+    pub fn new_from_sides(sides: [Rational; 3]) -> Option<ExactTriangle> {
+        Self::try_new_from_sides(sides).ok()
+    }
+    //same checks as validate_sides(), but exact
+    //TriangleError<Rational> is large, kept unboxed like the other try_ constructors
+    #[allow(clippy::result_large_err)]
+    pub fn try_new_from_sides(
+        sides: [Rational; 3],
+    ) -> Result<ExactTriangle, TriangleError<Rational>> {
+        for (index, side) in sides.iter().enumerate() {
+            if *side <= Rational::zero() {
+                return Err(TriangleError::NonPositiveSide {
+                    index,
+                    side: side.clone(),
+                });
+            }
+        }
+        let maxside = sides.iter().max().cloned().unwrap_or_else(Rational::zero);
+        let sum = sides[0].clone() + sides[1].clone() + sides[2].clone();
+        if maxside.clone() > sum - maxside {
+            return Err(TriangleError::TriangleInequality { sides });
+        }
+        Ok(ExactTriangle { sides })
+    }
+    pub fn new_from_integers(sides: [u64; 3]) -> Option<ExactTriangle> {
+        Self::new_from_sides(sides.map(Rational::from))
+    }
+    pub fn sides(&self) -> &[Rational; 3] {
+        &self.sides
+    }
+    pub fn sides_squared(&self) -> [Rational; 3] {
+        self.sides.clone().map(|side| side.clone() * side)
+    }
+    //16 * area^2 = 2a^2b^2 + 2b^2c^2 + 2c^2a^2 - a^4 - b^4 - c^4,
+    //an integer for integer sides
+    pub fn area_squared_16(&self) -> Rational {
+        let [a, b, c] = self.sides_squared();
+        let two = Rational::from(2);
+        two.clone() * a.clone() * b.clone()
+            + two.clone() * b.clone() * c.clone()
+            + two * c.clone() * a.clone()
+            - a.clone() * a
+            - b.clone() * b
+            - c.clone() * c
+    }
+    pub fn area_squared(&self) -> Rational {
+        self.area_squared_16() / Rational::from(16)
+    }
+    //None if the area is not rational
+    pub fn area(&self) -> Option<Rational> {
+        self.area_squared().sqrt()
+    }
+    pub fn cosine(&self, index: usize) -> Option<Rational> {
+        if index > 2 {
+            return None;
+        }
+        let squared = self.sides_squared();
+        let (j, k) = ((index + 1) % 3, (index + 2) % 3);
+        let numerator = squared[j].clone() + squared[k].clone() - squared[index].clone();
+        let denominator = Rational::from(2) * self.sides[j].clone() * self.sides[k].clone();
+        Some(numerator / denominator)
+    }
+    pub fn cosines(&self) -> [Rational; 3] {
+        [0, 1, 2].map(|i| self.cosine(i).unwrap())
+    }
+    pub fn sine_squared(&self, index: usize) -> Option<Rational> {
+        let cosine = self.cosine(index)?;
+        Some(Rational::one() - cosine.clone() * cosine)
+    }
+    pub fn sines_squared(&self) -> [Rational; 3] {
+        [0, 1, 2].map(|i| self.sine_squared(i).unwrap())
+    }
+    pub fn median_squared(&self, index: usize) -> Option<Rational> {
+        if index > 2 {
+            return None;
+        }
+        let squared = self.sides_squared();
+        let two = Rational::from(2);
+        let sum = two.clone() * squared[(index + 1) % 3].clone()
+            + two * squared[(index + 2) % 3].clone()
+            - squared[index].clone();
+        Some(sum / Rational::from(4))
+    }
+    pub fn medians_squared(&self) -> [Rational; 3] {
+        [0, 1, 2].map(|i| self.median_squared(i).unwrap())
+    }
+    pub fn height_squared(&self, index: usize) -> Option<Rational> {
+        if index > 2 {
+            return None;
+        }
+        let squared = self.sides_squared();
+        Some(Rational::from(4) * self.area_squared() / squared[index].clone())
+    }
+    pub fn heights_squared(&self) -> [Rational; 3] {
+        [0, 1, 2].map(|i| self.height_squared(i).unwrap())
+    }
+    //R^2 = a^2b^2c^2 / (16 * area^2), None for degenerate triangles
+    pub fn circumradius_squared(&self) -> Option<Rational> {
+        let area_squared_16 = self.area_squared_16();
+        if area_squared_16.is_zero() {
+            return None;
+        }
+        let [a, b, c] = self.sides_squared();
+        Some(a * b * c / area_squared_16)
+    }
+    //r^2 = area^2 / s^2
+    pub fn inradius_squared(&self) -> Rational {
+        let sp = self.semiperimeter();
+        self.area_squared() / (sp.clone() * sp)
+    }
+    //R / r = abc * s / (4 * area^2), None for degenerate triangles
+    pub fn radius_ratio(&self) -> Option<Rational> {
+        let area_squared = self.area_squared();
+        if area_squared.is_zero() {
+            return None;
+        }
+        let [a, b, c] = self.sides.clone();
+        Some(a * b * c * self.semiperimeter() / (Rational::from(4) * area_squared))
+    }
+    //R * r = abc / (4s)
+    pub fn radius_product(&self) -> Rational {
+        let [a, b, c] = self.sides.clone();
+        a * b * c / (Rational::from(4) * self.semiperimeter())
+    }
+    pub fn side_class(&self) -> SideClass {
+        let [a, b, c] = &self.sides;
+        match (a == b, b == c, c == a) {
+            (true, true, _) => SideClass::Equilateral,
+            (false, false, false) => SideClass::Scalene,
+            _ => SideClass::Isosceles,
+        }
+    }
+    pub fn angle_class(&self) -> AngleClass {
+        if self.area_squared_16().is_zero() {
+            return AngleClass::Degenerate;
+        }
+        let mut squared = self.sides_squared();
+        squared.sort();
+        let [a, b, c] = squared;
+        match (a + b).cmp(&c) {
+            std::cmp::Ordering::Equal => AngleClass::Right,
+            std::cmp::Ordering::Greater => AngleClass::Acute,
+            std::cmp::Ordering::Less => AngleClass::Obtuse,
+        }
+    }
+    pub fn is_right(&self) -> bool {
+        self.angle_class() == AngleClass::Right
+    }
+    pub fn is_degenerate(&self) -> bool {
+        self.angle_class() == AngleClass::Degenerate
+    }
+    //integer sides and integer, non-zero area
+    pub fn is_heronian(&self) -> bool {
+        self.sides.iter().all(Rational::is_integer)
+            && !self.is_degenerate()
+            && self.area().is_some_and(|area| area.is_integer())
+    }
+    //rounds the sides to f64
+    pub fn to_triangle(&self) -> Option<Triangle> {
+        Triangle::new_from_sides(self.sides.clone().map(|side| side.to_f64()))
+    }
+    fn semiperimeter(&self) -> Rational {
+        let [a, b, c] = self.sides.clone();
+        (a + b + c) / Rational::from(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::{
+        AngleClass, BigInt, ExactTriangle, Rational, SideClass, TriangleError,
+    };
+
+    fn ratio(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    #[test]
+    fn test_exact_quantities() -> Result<(), String> {
+        let egyptian = ExactTriangle::new_from_integers([5, 3, 4]).unwrap();
+        assert_eq!(egyptian.area_squared_16(), Rational::from(576));
+        assert_eq!(egyptian.area(), Some(Rational::from(6)));
+        assert_eq!(egyptian.cosines(), [ratio(0, 1), ratio(4, 5), ratio(3, 5)]);
+        assert_eq!(egyptian.medians_squared()[0], ratio(25, 4));
+        assert_eq!(egyptian.heights_squared()[0], ratio(144, 25));
+        assert_eq!(egyptian.circumradius_squared(), Some(ratio(25, 4)));
+        assert_eq!(egyptian.inradius_squared(), Rational::one());
+        assert_eq!(egyptian.radius_ratio(), Some(ratio(5, 2)));
+        assert_eq!(egyptian.radius_product(), ratio(5, 2));
+        assert!(egyptian.is_right() && egyptian.is_heronian());
+        assert_eq!(egyptian.side_class(), SideClass::Scalene);
+        let equilateral = ExactTriangle::new_from_integers([2, 2, 2]).unwrap();
+        assert_eq!(equilateral.area_squared(), Rational::from(3));
+        assert_eq!(equilateral.area(), None);
+        assert_eq!(equilateral.sines_squared()[1], ratio(3, 4));
+        assert!(ExactTriangle::new_from_integers([13, 14, 15])
+            .unwrap()
+            .is_heronian());
+        assert!(!ExactTriangle::new_from_integers([2, 3, 4])
+            .unwrap()
+            .is_heronian());
+        let flat = ExactTriangle::new_from_integers([1, 2, 3]).unwrap();
+        assert_eq!(flat.angle_class(), AngleClass::Degenerate);
+        assert_eq!(flat.circumradius_squared(), None);
+        assert_eq!(
+            ExactTriangle::try_new_from_sides([1u64, 2, 4].map(Rational::from)),
+            Err(TriangleError::TriangleInequality {
+                sides: [1u64, 2, 4].map(Rational::from)
+            })
+        );
+        let error: Box<dyn std::error::Error> = Box::new(
+            ExactTriangle::try_new_from_sides([0u64, 2, 4].map(Rational::from)).unwrap_err(),
+        );
+        assert_eq!(error.to_string(), "side 0 has non-positive length 0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_sides() -> Result<(), String> {
+        //a Pythagorean triple that f64 cannot represent
+        let (m, n) = (100_000_001_u64, 100_000_000_u64);
+        let sides = [m * m + n * n, m * m - n * n, 2 * m * n];
+        let right = ExactTriangle::new_from_integers(sides).unwrap();
+        assert!(right.is_right() && right.is_heronian());
+        let almost = ExactTriangle::new_from_integers([sides[0] + 1, sides[1], sides[2]]).unwrap();
+        assert_eq!(almost.angle_class(), AngleClass::Obtuse);
+        assert!(almost.to_triangle().unwrap().is_right(0.0000000000001));
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//number type used for the sides and all calculated properties, implement it
+//for your own type to use it in Triangle<T>
+pub trait Float:
+    Copy
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn pi() -> Self;
+    //difference below which two calculated values are considered equal
+    fn rounding_error() -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn zero() -> Self {
+        Self::from_f64(0.0)
+    }
+    fn one() -> Self {
+        Self::from_f64(1.0)
+    }
+    fn abs(self) -> Self {
+        if self < Self::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn pi() -> f64 {
+        core::f64::consts::PI
+    }
+    fn rounding_error() -> f64 {
+        0.0000000000001
+    }
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+    fn sin(self) -> f64 {
+        f64::sin(self)
+    }
+    fn cos(self) -> f64 {
+        f64::cos(self)
+    }
+    fn tan(self) -> f64 {
+        f64::tan(self)
+    }
+    fn asin(self) -> f64 {
+        f64::asin(self)
+    }
+    fn acos(self) -> f64 {
+        f64::acos(self)
+    }
+    fn atan(self) -> f64 {
+        f64::atan(self)
+    }
+    fn abs(self) -> f64 {
+        f64::abs(self)
+    }
+    fn max(self, other: f64) -> f64 {
+        f64::max(self, other)
+    }
+    fn min(self, other: f64) -> f64 {
+        f64::min(self, other)
+    }
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn pi() -> f32 {
+        core::f32::consts::PI
+    }
+    fn rounding_error() -> f32 {
+        0.00001
+    }
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+    fn sin(self) -> f32 {
+        f32::sin(self)
+    }
+    fn cos(self) -> f32 {
+        f32::cos(self)
+    }
+    fn tan(self) -> f32 {
+        f32::tan(self)
+    }
+    fn asin(self) -> f32 {
+        f32::asin(self)
+    }
+    fn acos(self) -> f32 {
+        f32::acos(self)
+    }
+    fn atan(self) -> f32 {
+        f32::atan(self)
+    }
+    fn abs(self) -> f32 {
+        f32::abs(self)
+    }
+    fn max(self, other: f32) -> f32 {
+        f32::max(self, other)
+    }
+    fn min(self, other: f32) -> f32 {
+        f32::min(self, other)
+    }
+}
@@ -0,0 +1,321 @@
+use super::{Float, GenericTriangle};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//closed interval that contains the true value, every operation rounds its
+//lower bound down and its upper bound up
+#[derive(Copy, Clone, Debug)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+pub type IntervalTriangle = GenericTriangle<Interval>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Validity {
+    Valid,
+    Invalid,
+    //the intervals contain both triangles and sides that are not a triangle
+    Undecided,
+}
+
+impl Interval {
+    pub fn new(lo: f64, hi: f64) -> Interval {
+        Interval {
+            lo: lo.min(hi),
+            hi: hi.max(lo),
+        }
+    }
+    pub fn point(value: f64) -> Interval {
+        Interval::new(value, value)
+    }
+    pub fn around(value: f64, radius: f64) -> Interval {
+        Interval::new((value - radius).next_down(), (value + radius).next_up())
+    }
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+    pub fn width(&self) -> f64 {
+        (self.hi - self.lo).next_up()
+    }
+    pub fn contains(&self, value: f64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+    fn whole() -> Interval {
+        Interval::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+    //rounded to nearest values are at most half of an ulp away
+    fn outward(lo: f64, hi: f64) -> Interval {
+        Interval::new(lo.next_down(), hi.next_up())
+    }
+    //library functions are not correctly rounded, so they get more room
+    fn outward_libm(lo: f64, hi: f64) -> Interval {
+        Interval::new(lo.next_down().next_down(), hi.next_up().next_up())
+    }
+    //whether phase + 2k * pi lies in the interval for some k, with a margin so
+    //rounding can only add points
+    fn reaches(&self, phase: f64) -> bool {
+        let margin = 0.000000001 * self.lo.abs().max(self.hi.abs()).max(1.0);
+        let k = ((self.lo - margin - phase) / TAU).ceil();
+        phase + k * TAU <= self.hi + margin
+    }
+    fn clamp_unit(self) -> Interval {
+        Interval::new(self.lo.max(-1.0), self.hi.min(1.0))
+    }
+}
+
+impl IntervalTriangle {
+    //sides have to be positive and every side shorter than the other two together
+    pub fn validity(sides: [Interval; 3]) -> Validity {
+        let [s0, s1, s2] = sides;
+        let margins = [s1 + s2 - s0, s2 + s0 - s1, s0 + s1 - s2];
+        let checks = sides.iter().chain(margins.iter());
+        if checks.clone().any(|value| value.hi <= 0.0) {
+            Validity::Invalid
+        } else if checks.clone().all(|value| value.lo > 0.0) {
+            Validity::Valid
+        } else {
+            Validity::Undecided
+        }
+    }
+}
+
+//intervals are equal only when both are the same point, an interval is
+//smaller than another one only when it is completely below it
+impl PartialEq for Interval {
+    fn eq(&self, other: &Interval) -> bool {
+        self.lo == self.hi && other.lo == other.hi && self.lo == other.lo
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<std::cmp::Ordering> {
+        if self == other {
+            Some(std::cmp::Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(std::cmp::Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(std::cmp::Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, other: Interval) -> Interval {
+        Interval::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, other: Interval) -> Interval {
+        Interval::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, other: Interval) -> Interval {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        let lo = products.iter().fold(f64::INFINITY, |min, &p| min.min(p));
+        let hi = products
+            .iter()
+            .fold(f64::NEG_INFINITY, |max, &p| max.max(p));
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+    fn div(self, other: Interval) -> Interval {
+        if other.lo <= 0.0 && other.hi >= 0.0 {
+            return Interval::whole();
+        }
+        self * Interval::outward(1.0 / other.hi, 1.0 / other.lo)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval::new(-self.hi, -self.lo)
+    }
+}
+
+impl Float for Interval {
+    fn from_f64(value: f64) -> Interval {
+        Interval::point(value)
+    }
+    //middle of the interval
+    fn to_f64(self) -> f64 {
+        self.lo / 2.0 + self.hi / 2.0
+    }
+    //f64 pi is just below the true value
+    fn pi() -> Interval {
+        Interval::new(PI, PI.next_up())
+    }
+    fn rounding_error() -> Interval {
+        Interval::point(f64::rounding_error())
+    }
+    fn sqrt(self) -> Interval {
+        if self.hi < 0.0 {
+            return Interval::point(f64::NAN);
+        }
+        Interval::outward(self.lo.max(0.0).sqrt(), self.hi.sqrt())
+    }
+    fn sin(self) -> Interval {
+        if self.width() >= TAU {
+            return Interval::new(-1.0, 1.0);
+        }
+        let (lo, hi) = (self.lo.sin(), self.hi.sin());
+        let mut result = Interval::outward_libm(lo.min(hi), lo.max(hi));
+        if self.reaches(FRAC_PI_2) {
+            result.hi = 1.0;
+        }
+        if self.reaches(-FRAC_PI_2) {
+            result.lo = -1.0;
+        }
+        result.clamp_unit()
+    }
+    fn cos(self) -> Interval {
+        if self.width() >= TAU {
+            return Interval::new(-1.0, 1.0);
+        }
+        let (lo, hi) = (self.lo.cos(), self.hi.cos());
+        let mut result = Interval::outward_libm(lo.min(hi), lo.max(hi));
+        if self.reaches(0.0) {
+            result.hi = 1.0;
+        }
+        if self.reaches(PI) {
+            result.lo = -1.0;
+        }
+        result.clamp_unit()
+    }
+    fn tan(self) -> Interval {
+        //poles are at pi / 2 + k * pi
+        if self.width() >= PI || self.reaches(FRAC_PI_2) || self.reaches(-FRAC_PI_2) {
+            return Interval::whole();
+        }
+        Interval::outward_libm(self.lo.tan(), self.hi.tan())
+    }
+    fn asin(self) -> Interval {
+        if self.hi < -1.0 || self.lo > 1.0 {
+            return Interval::point(f64::NAN);
+        }
+        let x = self.clamp_unit();
+        Interval::outward_libm(x.lo.asin(), x.hi.asin())
+    }
+    fn acos(self) -> Interval {
+        if self.hi < -1.0 || self.lo > 1.0 {
+            return Interval::point(f64::NAN);
+        }
+        let x = self.clamp_unit();
+        Interval::outward_libm(x.hi.acos(), x.lo.acos())
+    }
+    fn atan(self) -> Interval {
+        Interval::outward_libm(self.lo.atan(), self.hi.atan())
+    }
+    fn abs(self) -> Interval {
+        if self.lo >= 0.0 {
+            self
+        } else if self.hi <= 0.0 {
+            -self
+        } else {
+            Interval::new(0.0, self.hi.max(-self.lo))
+        }
+    }
+    fn max(self, other: Interval) -> Interval {
+        Interval::new(self.lo.max(other.lo), self.hi.max(other.hi))
+    }
+    fn min(self, other: Interval) -> Interval {
+        Interval::new(self.lo.min(other.lo), self.hi.min(other.hi))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::{Float, Interval, IntervalTriangle, Validity};
+
+    #[test]
+    fn test_enclosures() -> Result<(), String> {
+        let sides = [5.0, 3.0, 4.0].map(Interval::point);
+        let triangle = IntervalTriangle::new_from_sides(sides).unwrap();
+        let area = triangle.area().unwrap();
+        assert!(area.contains(6.0) && area.width() < 0.000000000001);
+        assert!(triangle.circumradius().unwrap().contains(2.5));
+        assert!(triangle.inradius().unwrap().contains(1.0));
+        //the true right angle is not a double, so check the enclosure of pi / 2
+        let angle = triangle.angle(0).unwrap();
+        let half_pi = Interval::pi() / Interval::point(2.0);
+        assert!(angle.lo() <= half_pi.hi() && half_pi.lo() <= angle.hi());
+        assert!(angle.width() < 0.000000000001);
+        //area of 7, 8, 9 is 12 * sqrt(5)
+        let triangle =
+            IntervalTriangle::new_from_sides([7.0, 8.0, 9.0].map(Interval::point)).unwrap();
+        let area = triangle.area().unwrap();
+        let expected = Interval::point(12.0) * Interval::point(5.0).sqrt();
+        assert!(area.lo() <= expected.hi() && expected.lo() <= area.hi());
+        Ok(())
+    }
+
+    #[test]
+    fn test_interval_operations() -> Result<(), String> {
+        let x = Interval::new(-1.0, 2.0);
+        assert_eq!(x.partial_cmp(&Interval::point(1.0)), None);
+        assert!(x < Interval::point(3.0));
+        let square = x * x;
+        assert!(square.lo() <= -2.0 && square.hi() >= 4.0);
+        let sine = Interval::new(1.0, 2.0).sin();
+        assert!(sine.hi() == 1.0 && sine.lo() <= 1.0_f64.sin());
+        let cosine = Interval::new(3.0, 3.5).cos();
+        assert_eq!(cosine.lo(), -1.0);
+        assert_eq!((Interval::point(1.0) / x).lo(), f64::NEG_INFINITY);
+        let third = Interval::point(1.0) / Interval::point(3.0);
+        assert!(third.lo() < third.hi() && (third * Interval::point(3.0)).contains(1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validity() -> Result<(), String> {
+        let sides = |values: [(f64, f64); 3]| values.map(|(lo, hi)| Interval::new(lo, hi));
+        let valid = sides([(2.9, 3.1), (3.9, 4.1), (4.9, 5.1)]);
+        assert_eq!(IntervalTriangle::validity(valid), Validity::Valid);
+        let invalid = sides([(1.0, 1.1), (2.0, 2.1), (3.5, 3.6)]);
+        assert_eq!(IntervalTriangle::validity(invalid), Validity::Invalid);
+        let undecided = sides([(1.0, 1.0), (2.0, 2.0), (2.9, 3.1)]);
+        assert_eq!(IntervalTriangle::validity(undecided), Validity::Undecided);
+        let negative = sides([(-1.0, -0.5), (2.0, 2.0), (2.0, 2.0)]);
+        assert_eq!(IntervalTriangle::validity(negative), Validity::Invalid);
+        //the constructors agree with validity, also for wide intervals
+        let wide = sides([(1.0, 1.5), (1.0, 1.5), (1.5, 1.9)]);
+        assert_eq!(IntervalTriangle::validity(wide), Validity::Valid);
+        let triangle = IntervalTriangle::try_new_from_sides(wide).map_err(|e| e.to_string())?;
+        //sides 1.25, 1.25 and 1.5 have the area 0.75
+        assert!(triangle.area().unwrap().contains(0.75));
+        assert!(IntervalTriangle::try_new_blank(valid).is_ok());
+        assert!(IntervalTriangle::try_new_blank(undecided).is_err());
+        let unsigned = sides([(-0.1, 1.0), (2.0, 2.0), (2.0, 2.0)]);
+        assert_eq!(IntervalTriangle::validity(unsigned), Validity::Undecided);
+        assert!(IntervalTriangle::try_new_blank(unsigned).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use super::{Float, GenericTriangle, Slot, TriangleError};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+}
+
+//points of the vertices, vertex i is opposite to sides[i]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Placement<T> {
+    Plane([(T, T); 3]),
+    Space([(T, T, T); 3]),
+}
+
+//a point in the plane or in the space of a placement
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Point<T> {
+    Plane(T, T),
+    Space(T, T, T),
+}
+
+impl<T: Float> Placement<T> {
+    pub fn sides(&self) -> [T; 3] {
+        let squared = match self {
+            Placement::Plane(v) => [0, 1, 2].map(|i| {
+                let (p, q) = (v[(i + 1) % 3], v[(i + 2) % 3]);
+                (p.0 - q.0) * (p.0 - q.0) + (p.1 - q.1) * (p.1 - q.1)
+            }),
+            Placement::Space(v) => [0, 1, 2].map(|i| {
+                let (p, q) = (v[(i + 1) % 3], v[(i + 2) % 3]);
+                (p.0 - q.0) * (p.0 - q.0) + (p.1 - q.1) * (p.1 - q.1) + (p.2 - q.2) * (p.2 - q.2)
+            }),
+        };
+        squared.map(|side| side.sqrt())
+    }
+    //length of the cross product of the two sides from vertex 0, twice the area
+    fn cross(&self) -> T {
+        match self {
+            Placement::Plane(v) => ((v[1].0 - v[0].0) * (v[2].1 - v[0].1)
+                - (v[2].0 - v[0].0) * (v[1].1 - v[0].1))
+                .abs(),
+            Placement::Space(v) => {
+                let u = (v[1].0 - v[0].0, v[1].1 - v[0].1, v[1].2 - v[0].2);
+                let w = (v[2].0 - v[0].0, v[2].1 - v[0].1, v[2].2 - v[0].2);
+                let x = u.1 * w.2 - u.2 * w.1;
+                let y = u.2 * w.0 - u.0 * w.2;
+                let z = u.0 * w.1 - u.1 * w.0;
+                (x * x + y * y + z * z).sqrt()
+            }
+        }
+    }
+    //point with normalized barycentric coordinates, the weights of the vertices
+    pub fn combine(&self, weights: [T; 3]) -> Point<T> {
+        let [w0, w1, w2] = weights;
+        match self {
+            Placement::Plane(v) => Point::Plane(
+                w0 * v[0].0 + w1 * v[1].0 + w2 * v[2].0,
+                w0 * v[0].1 + w1 * v[1].1 + w2 * v[2].1,
+            ),
+            Placement::Space(v) => Point::Space(
+                w0 * v[0].0 + w1 * v[1].0 + w2 * v[2].0,
+                w0 * v[0].1 + w1 * v[1].1 + w2 * v[2].1,
+                w0 * v[0].2 + w1 * v[1].2 + w2 * v[2].2,
+            ),
+        }
+    }
+    //vertex 0 stays in place
+    pub(super) fn scale(self, factor: T) -> Placement<T> {
+        match self {
+            Placement::Plane(v) => Placement::Plane(v.map(|p| {
+                (
+                    v[0].0 + (p.0 - v[0].0) * factor,
+                    v[0].1 + (p.1 - v[0].1) * factor,
+                )
+            })),
+            Placement::Space(v) => Placement::Space(v.map(|p| {
+                (
+                    v[0].0 + (p.0 - v[0].0) * factor,
+                    v[0].1 + (p.1 - v[0].1) * factor,
+                    v[0].2 + (p.2 - v[0].2) * factor,
+                )
+            })),
+        }
+    }
+}
+
+impl<T: Float> GenericTriangle<T> {
+    pub fn new_from_points(points: [(T, T); 3]) -> Option<GenericTriangle<T>> {
+        Self::try_new_from_points(points).ok()
+    }
+    pub fn try_new_from_points(
+        points: [(T, T); 3],
+    ) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::try_new_from_placement(Placement::Plane(points))
+    }
+    pub fn new_from_points_3d(points: [(T, T, T); 3]) -> Option<GenericTriangle<T>> {
+        Self::try_new_from_points_3d(points).ok()
+    }
+    pub fn try_new_from_points_3d(
+        points: [(T, T, T); 3],
+    ) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        Self::try_new_from_placement(Placement::Space(points))
+    }
+    fn try_new_from_placement(
+        placement: Placement<T>,
+    ) -> Result<GenericTriangle<T>, TriangleError<T>> {
+        let sides = placement.sides();
+        let maxside = GenericTriangle::max_side(sides);
+        //also catches points that are in the same place
+        if placement.cross() <= T::rounding_error() * maxside * maxside {
+            return Err(TriangleError::Collinear { points: placement });
+        }
+        let mut triangle = Self::try_new_from_sides(sides)?;
+        triangle.placement = Some(placement);
+        Ok(triangle)
+    }
+}
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //vertex i is opposite to sides[i], vertex 0 is at the origin, side 2
+    //goes along the x axis to vertex 1 and vertex 2 is above it
+    pub fn vertices(&self) -> [(T, T); 3] {
+        let side_1 = self.sides[1];
+        let side_2 = self.sides[2];
+        let cos = self.cosine(0).unwrap();
+        let sin = self.sine(0).unwrap();
+        [
+            (T::zero(), T::zero()),
+            (side_2, T::zero()),
+            (side_1 * cos, side_1 * sin),
+        ]
+    }
+    pub fn placement(&self) -> Option<Placement<T>> {
+        self.placement
+    }
+    //stores the points from vertices_with() as the placement of the triangle
+    pub fn place(&mut self, anchor: (T, T), rotation: T, orientation: Orientation) {
+        self.placement = Some(Placement::Plane(self.vertices_with(
+            anchor,
+            rotation,
+            orientation,
+        )));
+    }
+    //vertex 0 is moved to the anchor and side 2 is rotated by the angle in radians
+    //(counterclockwise) from the x axis, clockwise orientation mirrors vertex 2
+    pub fn vertices_with(
+        &self,
+        anchor: (T, T),
+        rotation: T,
+        orientation: Orientation,
+    ) -> [(T, T); 3] {
+        let (sin, cos) = (rotation.sin(), rotation.cos());
+        self.vertices().map(|(x, y)| {
+            let y = match orientation {
+                Orientation::CounterClockwise => y,
+                Orientation::Clockwise => -y,
+            };
+            (anchor.0 + x * cos - y * sin, anchor.1 + x * sin + y * cos)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::{Orientation, Placement, Triangle, TriangleError};
+
+    fn distance(p: (f64, f64), q: (f64, f64)) -> f64 {
+        ((p.0 - q.0) * (p.0 - q.0) + (p.1 - q.1) * (p.1 - q.1)).sqrt()
+    }
+
+    fn signed_area(v: [(f64, f64); 3]) -> f64 {
+        ((v[1].0 - v[0].0) * (v[2].1 - v[0].1) - (v[2].0 - v[0].0) * (v[1].1 - v[0].1)) / 2.0
+    }
+
+    #[test]
+    fn test_vertices() -> Result<(), String> {
+        let egyptian = Triangle::new_from_sides([3.0, 4.0, 5.0]).unwrap();
+        let v = egyptian.vertices();
+        assert_eq!(v[0], (0.0, 0.0));
+        assert_eq!(v[1], (5.0, 0.0));
+        assert!(distance(v[2], (3.2, 2.4)) < ROUNDING_ERROR);
+        for i in 0..3 {
+            let side = distance(v[(i + 1) % 3], v[(i + 2) % 3]);
+            assert!((side - egyptian.side(i).unwrap()).abs() < ROUNDING_ERROR);
+        }
+        assert!((signed_area(v) - 6.0).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vertices_with() -> Result<(), String> {
+        use core::f64::consts::PI;
+        let obtuse = Triangle::new_from_sides([2.0, 3.0, 4.5]).unwrap();
+        let area = obtuse.area().unwrap();
+        let v = obtuse.vertices_with((1.0, -2.0), PI / 2.0, Orientation::Clockwise);
+        assert_eq!(v[0], (1.0, -2.0));
+        assert!(distance(v[1], (1.0, 2.5)) < ROUNDING_ERROR);
+        for i in 0..3 {
+            let side = distance(v[(i + 1) % 3], v[(i + 2) % 3]);
+            assert!((side - obtuse.side(i).unwrap()).abs() < ROUNDING_ERROR);
+        }
+        assert!((signed_area(v) + area).abs() < ROUNDING_ERROR);
+        let v = obtuse.vertices_with((0.0, 0.0), 1.0, Orientation::CounterClockwise);
+        assert!((signed_area(v) - area).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_points() -> Result<(), String> {
+        let plane = Triangle::new_from_points([(1.0, 1.0), (4.0, 1.0), (1.0, 5.0)]).unwrap();
+        assert_eq!(plane.sides(), [Some(5.0), Some(4.0), Some(3.0)]);
+        assert!(plane.is_right(ROUNDING_ERROR));
+        assert!(matches!(plane.placement(), Some(Placement::Plane(_))));
+        let space =
+            Triangle::new_from_points_3d([(0.0, 0.0, 1.0), (0.0, 3.0, 1.0), (4.0, 0.0, 1.0)])
+                .unwrap();
+        assert!((space.area().unwrap() - 6.0).abs() < ROUNDING_ERROR);
+        assert_eq!(space.sides(), [Some(5.0), Some(4.0), Some(3.0)]);
+        let collinear = [(0.0, 0.0), (1.0, 1.0), (3.0, 3.0)];
+        assert_eq!(
+            Triangle::try_new_from_points(collinear).unwrap_err(),
+            TriangleError::Collinear {
+                points: Placement::Plane(collinear)
+            }
+        );
+        assert!(Triangle::try_new_from_points_3d([
+            (1.0, 2.0, 3.0),
+            (1.0, 2.0, 3.0),
+            (0.0, 0.0, 0.0)
+        ])
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_placement_changes() -> Result<(), String> {
+        let mut triangle = Triangle::new_from_points([(1.0, 1.0), (4.0, 1.0), (1.0, 5.0)]).unwrap();
+        triangle.scale(2.0).unwrap();
+        assert_eq!(
+            triangle.placement(),
+            Some(Placement::Plane([(1.0, 1.0), (7.0, 1.0), (1.0, 9.0)]))
+        );
+        triangle.set_side(0, 9.0).unwrap();
+        assert_eq!(triangle.placement(), None);
+        triangle.place((2.0, 0.0), 0.0, Orientation::CounterClockwise);
+        let Some(Placement::Plane(v)) = triangle.placement() else {
+            return Err(String::from("triangle is not placed"));
+        };
+        assert_eq!(v[0], (2.0, 0.0));
+        assert!((distance(v[1], v[2]) - 9.0).abs() < ROUNDING_ERROR);
+        Ok(())
+    }
+}
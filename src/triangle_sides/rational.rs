@@ -0,0 +1,521 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//magnitude of a big integer, 32 bit digits from the lowest one, without
+//leading zero digits, so zero has no digits
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Natural(Vec<u32>);
+
+impl Natural {
+    fn from_u128(mut value: u128) -> Natural {
+        let mut digits = Vec::new();
+        while value > 0 {
+            digits.push(value as u32);
+            value >>= 32;
+        }
+        Natural(digits)
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+    fn trim(mut self) -> Natural {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+    fn add(&self, other: &Natural) -> Natural {
+        let mut digits = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let sum =
+                *self.0.get(i).unwrap_or(&0) as u64 + *other.0.get(i).unwrap_or(&0) as u64 + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+        Natural(digits).trim()
+    }
+    //other must not be bigger
+    fn sub(&self, other: &Natural) -> Natural {
+        let mut digits = Vec::with_capacity(self.0.len());
+        let mut borrow = 0i64;
+        for (i, &digit) in self.0.iter().enumerate() {
+            let mut difference = digit as i64 - *other.0.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = 0;
+            if difference < 0 {
+                difference += 1 << 32;
+                borrow = 1;
+            }
+            digits.push(difference as u32);
+        }
+        Natural(digits).trim()
+    }
+    fn mul(&self, other: &Natural) -> Natural {
+        let mut digits = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &x) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in other.0.iter().enumerate() {
+                let product = x as u64 * y as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            digits[i + other.0.len()] = carry as u32;
+        }
+        Natural(digits).trim()
+    }
+    fn bits(&self) -> usize {
+        match self.0.last() {
+            Some(last) => self.0.len() * 32 - last.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+    fn bit(&self, index: usize) -> bool {
+        self.0[index / 32] >> (index % 32) & 1 == 1
+    }
+    fn power_of_two(exponent: usize) -> Natural {
+        let mut digits = vec![0u32; exponent / 32 + 1];
+        digits[exponent / 32] = 1 << (exponent % 32);
+        Natural(digits)
+    }
+    fn half(&self) -> Natural {
+        let mut digits = self.0.clone();
+        for i in 0..digits.len() {
+            let next = digits.get(i + 1).map_or(0, |&digit| digit << 31);
+            digits[i] = digits[i] >> 1 | next;
+        }
+        Natural(digits).trim()
+    }
+    //long division, one bit at a time
+    fn div_rem(&self, other: &Natural) -> (Natural, Natural) {
+        assert!(!other.is_zero(), "division by zero");
+        let mut quotient = vec![0u32; self.0.len()];
+        let mut remainder = Natural(Vec::new());
+        for i in (0..self.bits()).rev() {
+            remainder = remainder.add(&remainder);
+            if self.bit(i) {
+                remainder = remainder.add(&Natural(vec![1]));
+            }
+            if remainder >= *other {
+                remainder = remainder.sub(other);
+                quotient[i / 32] |= 1 << (i % 32);
+            }
+        }
+        (Natural(quotient).trim(), remainder)
+    }
+    fn div_rem_small(&self, divisor: u32) -> (Natural, u32) {
+        let mut digits = self.0.clone();
+        let mut remainder = 0u64;
+        for digit in digits.iter_mut().rev() {
+            let current = remainder << 32 | *digit as u64;
+            *digit = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        (Natural(digits).trim(), remainder as u32)
+    }
+    //largest number whose square is not bigger, Newton's method from above
+    fn isqrt(&self) -> Natural {
+        if self.is_zero() {
+            return Natural(Vec::new());
+        }
+        let mut root = Natural::power_of_two(self.bits().div_ceil(2));
+        loop {
+            let next = root.add(&self.div_rem(&root).0).half();
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+    fn to_f64(&self) -> f64 {
+        self.0
+            .iter()
+            .rev()
+            .fold(0.0, |value, &digit| value * 4294967296.0 + digit as f64)
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Natural) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Natural) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Natural,
+}
+
+impl BigInt {
+    fn new(negative: bool, magnitude: Natural) -> BigInt {
+        //zero is never negative
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+    pub fn zero() -> BigInt {
+        BigInt::from(0)
+    }
+    pub fn one() -> BigInt {
+        BigInt::from(1)
+    }
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    pub fn abs(&self) -> BigInt {
+        BigInt::new(false, self.magnitude.clone())
+    }
+    //quotient rounded towards zero and the remainder with the sign of self
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = self.magnitude.div_rem(&other.magnitude);
+        (
+            BigInt::new(self.negative != other.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        )
+    }
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let (mut a, mut b) = (self.magnitude.clone(), other.magnitude.clone());
+        while !b.is_zero() {
+            let remainder = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        BigInt::new(false, a)
+    }
+    //None for negative numbers
+    pub fn isqrt(&self) -> Option<BigInt> {
+        if self.negative {
+            return None;
+        }
+        Some(BigInt::new(false, self.magnitude.isqrt()))
+    }
+    pub fn is_square(&self) -> bool {
+        self.isqrt()
+            .is_some_and(|root| root.clone() * root == *self)
+    }
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.magnitude.to_f64();
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> BigInt {
+        BigInt::new(value < 0, Natural::from_u128(value.unsigned_abs()))
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> BigInt {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> BigInt {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> BigInt {
+        BigInt::from(value as i128)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+    fn add(self, other: BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, self.magnitude.add(&other.magnitude));
+        }
+        if self.magnitude >= other.magnitude {
+            BigInt::new(self.negative, self.magnitude.sub(&other.magnitude))
+        } else {
+            BigInt::new(other.negative, other.magnitude.sub(&self.magnitude))
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+    fn sub(self, other: BigInt) -> BigInt {
+        self + -other
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+    fn mul(self, other: BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            self.magnitude.mul(&other.magnitude),
+        )
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        //groups of 9 decimal digits from the lowest one
+        let mut groups = Vec::new();
+        let mut rest = self.magnitude.clone();
+        while !rest.is_zero() {
+            let (quotient, group) = rest.div_rem_small(1_000_000_000);
+            groups.push(group);
+            rest = quotient;
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        match groups.split_last() {
+            Some((first, others)) => {
+                write!(f, "{}", first)?;
+                for group in others.iter().rev() {
+                    write!(f, "{:09}", group)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "0"),
+        }
+    }
+}
+
+//fraction in lowest terms with a positive denominator
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    //panics when the denominator is zero, like integer division
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Rational {
+        assert!(!denominator.is_zero(), "zero denominator");
+        let gcd = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator) =
+            (numerator.div_rem(&gcd).0, denominator.div_rem(&gcd).0);
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        Rational {
+            numerator,
+            denominator,
+        }
+    }
+    pub fn zero() -> Rational {
+        Rational::from(0)
+    }
+    pub fn one() -> Rational {
+        Rational::from(1)
+    }
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInt::one()
+    }
+    //exact square root, None if it is not rational
+    pub fn sqrt(&self) -> Option<Rational> {
+        let numerator = self.numerator.isqrt()?;
+        let denominator = self.denominator.isqrt()?;
+        let root = Rational::new(numerator, denominator);
+        (root.clone() * root.clone() == *self).then_some(root)
+    }
+    pub fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: BigInt::one(),
+        }
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Rational {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Rational {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl From<u64> for Rational {
+    fn from(value: u64) -> Rational {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        (self.numerator.clone() * other.denominator.clone())
+            .cmp(&(other.numerator.clone() * self.denominator.clone()))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.denominator.clone() + other.numerator * self.denominator.clone(),
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::{BigInt, Rational};
+
+    #[test]
+    fn test_big_int() -> Result<(), String> {
+        let big = BigInt::from(u64::MAX) * BigInt::from(u64::MAX);
+        assert_eq!(big.to_string(), "340282366920938463426481119284349108225");
+        assert_eq!(big.isqrt(), Some(BigInt::from(u64::MAX)));
+        assert!(big.is_square());
+        assert!(!(big.clone() + BigInt::one()).is_square());
+        let (quotient, remainder) =
+            (big.clone() + BigInt::from(5)).div_rem(&BigInt::from(u64::MAX));
+        assert_eq!(
+            (quotient, remainder),
+            (BigInt::from(u64::MAX), BigInt::from(5))
+        );
+        assert_eq!(
+            BigInt::from(-7).div_rem(&BigInt::from(2)),
+            (BigInt::from(-3), BigInt::from(-1))
+        );
+        assert_eq!((BigInt::from(3) - big.clone() + big).to_string(), "3");
+        assert!(BigInt::from(-2) < BigInt::from(1));
+        assert_eq!(BigInt::from(-1_000_000_000_i64).to_string(), "-1000000000");
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational() -> Result<(), String> {
+        let third = Rational::new(BigInt::from(2), BigInt::from(-6));
+        assert_eq!(third.to_string(), "-1/3");
+        assert_eq!(third.numerator(), &BigInt::from(-1));
+        let sum = third.clone() + Rational::from(1);
+        assert_eq!(sum, Rational::new(BigInt::from(2), BigInt::from(3)));
+        assert_eq!((sum.clone() / third.clone()).to_string(), "-2");
+        assert!(third < sum);
+        let square = Rational::new(BigInt::from(9), BigInt::from(49));
+        assert_eq!(
+            square.sqrt(),
+            Some(Rational::new(BigInt::from(3), BigInt::from(7)))
+        );
+        assert_eq!(sum.sqrt(), None);
+        assert!((sum.to_f64() - 2.0 / 3.0).abs() < 0.0000000000001);
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use super::{Float, GenericTriangle, Point, Slot};
+use std::sync::Arc;
+
+//first coordinate of a center from the sides [a, b, c], the other two are
+//f(b, c, a) and f(c, a, b)
+pub type CenterFn<T> = Arc<dyn Fn(T, T, T) -> T + Send + Sync>;
+
+#[derive(Clone)]
+pub enum CenterFunction<T> {
+    Barycentric(CenterFn<T>),
+    //distances to the sides, barycentric coordinates are them multiplied by the sides
+    Trilinear(CenterFn<T>),
+}
+
+impl<T: Float> CenterFunction<T> {
+    //normalized barycentric coordinates, None if the center is not defined
+    pub fn barycentric(&self, sides: [T; 3]) -> Option<[T; 3]> {
+        let [a, b, c] = sides;
+        let weights = match self {
+            CenterFunction::Barycentric(f) => [f(a, b, c), f(b, c, a), f(c, a, b)],
+            CenterFunction::Trilinear(f) => [a * f(a, b, c), b * f(b, c, a), c * f(c, a, b)],
+        };
+        let sum = weights[0] + weights[1] + weights[2];
+        //relative to the weights, which grow with a power of the scale
+        let size = weights[0].abs() + weights[1].abs() + weights[2].abs();
+        //also catches NaN
+        match sum.abs().partial_cmp(&(T::rounding_error() * size)) {
+            Some(std::cmp::Ordering::Greater) => Some(weights.map(|weight| weight / sum)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NamedCenter<T> {
+    pub name: String,
+    //n of X(n) in Kimberling's Encyclopedia of Triangle Centers
+    pub kimberling: Option<u32>,
+    pub function: CenterFunction<T>,
+}
+
+#[derive(Clone)]
+pub struct CenterRegistry<T> {
+    centers: Vec<NamedCenter<T>>,
+}
+
+impl<T: Float + 'static> Default for CenterRegistry<T> {
+    fn default() -> CenterRegistry<T> {
+        CenterRegistry::new()
+    }
+}
+
+impl<T: Float + 'static> CenterRegistry<T> {
+    //registry with the built-in centers
+    pub fn new() -> CenterRegistry<T> {
+        let mut registry = CenterRegistry::empty();
+        let two = || T::from_f64(2.0);
+        //sides[i] * sides[j] * cos(angle k) for the angles opposite to a, b and c
+        let conway = move |a: T, b: T, c: T| {
+            let (a2, b2, c2) = (a * a, b * b, c * c);
+            [
+                (b2 + c2 - a2) / two(),
+                (c2 + a2 - b2) / two(),
+                (a2 + b2 - c2) / two(),
+            ]
+        };
+        let centers: [(&str, u32, CenterFunction<T>); 11] = [
+            ("incenter", 1, barycentric(|a, _, _| a)),
+            ("centroid", 2, barycentric(|_, _, _| T::one())),
+            (
+                "circumcenter",
+                3,
+                barycentric(move |a, b, c| a * a * conway(a, b, c)[0]),
+            ),
+            (
+                "orthocenter",
+                4,
+                barycentric(move |a, b, c| {
+                    let [_, sb, sc] = conway(a, b, c);
+                    sb * sc
+                }),
+            ),
+            (
+                "nine-point center",
+                5,
+                barycentric(move |a, b, c| {
+                    let [sa, sb, sc] = conway(a, b, c);
+                    sa * sb + sa * sc + two() * sb * sc
+                }),
+            ),
+            ("symmedian point", 6, barycentric(|a, _, _| a * a)),
+            (
+                "Gergonne point",
+                7,
+                barycentric(|a, b, c| T::one() / (b + c - a)),
+            ),
+            ("Nagel point", 8, barycentric(|a, b, c| b + c - a)),
+            ("Spieker center", 10, barycentric(|_, b, c| b + c)),
+            (
+                "Fermat point",
+                13,
+                CenterFunction::Trilinear(Arc::new(move |a, b, c| {
+                    let angle = ((b * b + c * c - a * a) / (two() * b * c)).acos();
+                    T::one() / (angle + T::pi() / T::from_f64(3.0)).sin()
+                })),
+            ),
+            (
+                "de Longchamps point",
+                20,
+                barycentric(move |a, b, c| {
+                    let [sa, sb, sc] = conway(a, b, c);
+                    sa * sb + sa * sc - sb * sc
+                }),
+            ),
+        ];
+        for (name, number, function) in centers {
+            registry.register(name, Some(number), function);
+        }
+        registry
+    }
+    pub fn empty() -> CenterRegistry<T> {
+        CenterRegistry {
+            centers: Vec::new(),
+        }
+    }
+    //replaces the center with the same name and returns it
+    pub fn register(
+        &mut self,
+        name: &str,
+        kimberling: Option<u32>,
+        function: CenterFunction<T>,
+    ) -> Option<NamedCenter<T>> {
+        let center = NamedCenter {
+            name: String::from(name),
+            kimberling,
+            function,
+        };
+        match self.position(name) {
+            Some(index) => Some(std::mem::replace(&mut self.centers[index], center)),
+            None => {
+                self.centers.push(center);
+                None
+            }
+        }
+    }
+    //names are not case sensitive
+    pub fn get(&self, name: &str) -> Option<&NamedCenter<T>> {
+        self.position(name).map(|index| &self.centers[index])
+    }
+    pub fn kimberling(&self, number: u32) -> Option<&NamedCenter<T>> {
+        self.centers
+            .iter()
+            .find(|center| center.kimberling == Some(number))
+    }
+    pub fn names(&self) -> Vec<&str> {
+        self.centers
+            .iter()
+            .map(|center| center.name.as_str())
+            .collect()
+    }
+    pub fn evaluate<S: Slot<T>>(
+        &self,
+        name: &str,
+        triangle: &GenericTriangle<T, S>,
+    ) -> Option<[T; 3]> {
+        triangle.center_of(&self.get(name)?.function)
+    }
+    fn position(&self, name: &str) -> Option<usize> {
+        self.centers
+            .iter()
+            .position(|center| center.name.eq_ignore_ascii_case(name))
+    }
+}
+
+fn barycentric<T, F: Fn(T, T, T) -> T + Send + Sync + 'static>(f: F) -> CenterFunction<T> {
+    CenterFunction::Barycentric(Arc::new(f))
+}
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //normalized barycentric coordinates of any center function
+    pub fn center_of(&self, function: &CenterFunction<T>) -> Option<[T; 3]> {
+        function.barycentric(self.sides)
+    }
+    pub fn center_point_of(&self, function: &CenterFunction<T>) -> Option<Point<T>> {
+        let placement = self.placement?;
+        Some(placement.combine(self.center_of(function)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::{same, ROUNDING_ERROR};
+    use crate::triangle_sides::{Center, CenterFunction, CenterRegistry, Point, Triangle};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_kimberling_centers() -> Result<(), String> {
+        let registry = CenterRegistry::new();
+        let triangle = Triangle::new_from_sides([7.0, 4.0, 5.0]).unwrap();
+        let x = |n: u32| triangle.center_of(&registry.kimberling(n).unwrap().function);
+        let classical = [
+            Center::Incenter,
+            Center::Centroid,
+            Center::Circumcenter,
+            Center::Orthocenter,
+        ];
+        for (n, center) in (1..).zip(classical) {
+            assert!(same(x(n).unwrap(), triangle.barycentric(center)));
+        }
+        let [i, g, o, h] = [1, 2, 3, 4].map(|n| x(n).unwrap());
+        //nine-point center halves OH, de Longchamps point is H reflected in O
+        assert!(same(x(5).unwrap(), [0, 1, 2].map(|k| (o[k] + h[k]) / 2.0)));
+        assert!(same(x(20).unwrap(), [0, 1, 2].map(|k| 2.0 * o[k] - h[k])));
+        //G divides IN in 1:2, Spieker center halves IN
+        let n = x(8).unwrap();
+        assert!(same(g, [0, 1, 2].map(|k| (2.0 * i[k] + n[k]) / 3.0)));
+        assert!(same(x(10).unwrap(), [0, 1, 2].map(|k| (i[k] + n[k]) / 2.0)));
+        assert!(same(
+            registry.evaluate("Symmedian Point", &triangle).unwrap(),
+            [49.0 / 90.0, 16.0 / 90.0, 25.0 / 90.0]
+        ));
+        assert_eq!(registry.evaluate("unknown", &triangle), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_small_triangle() -> Result<(), String> {
+        let registry = CenterRegistry::new();
+        let large = Triangle::new_from_sides([7.0, 4.0, 5.0]).unwrap();
+        let small = Triangle::new_from_sides([7e-4, 4e-4, 5e-4]).unwrap();
+        for name in registry.names() {
+            let expected = registry.evaluate(name, &large).unwrap();
+            assert!(same(registry.evaluate(name, &small).unwrap(), expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fermat_point() -> Result<(), String> {
+        let registry = CenterRegistry::new();
+        let triangle = Triangle::new_from_points([(0.0, 0.0), (4.0, 0.0), (1.0, 3.0)]).unwrap();
+        let function = &registry.get("fermat point").unwrap().function;
+        let Some(Point::Plane(x, y)) = triangle.center_point_of(function) else {
+            return Err(String::from("no Fermat point"));
+        };
+        //every side is seen from the Fermat point at 120 degrees
+        let v = [(0.0, 0.0), (4.0, 0.0), (1.0, 3.0)].map(|(vx, vy)| (vx - x, vy - y));
+        for k in 0..3 {
+            let (p, q) = (v[k], v[(k + 1) % 3]);
+            let cos = (p.0 * q.0 + p.1 * q.1) / (p.0.hypot(p.1) * q.0.hypot(q.1));
+            assert!((cos + 0.5).abs() < ROUNDING_ERROR * 10.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_register() -> Result<(), String> {
+        let mut registry = CenterRegistry::empty();
+        assert!(registry.names().is_empty());
+        let mittenpunkt = CenterFunction::Trilinear(Arc::new(|a: f64, b: f64, c: f64| b + c - a));
+        assert!(registry
+            .register("Mittenpunkt", Some(9), mittenpunkt.clone())
+            .is_none());
+        assert!(registry
+            .register("mittenpunkt", Some(9), mittenpunkt)
+            .is_some());
+        assert_eq!(registry.names(), vec!["mittenpunkt"]);
+        let equilateral = Triangle::new_from_sides([2.0, 2.0, 2.0]).unwrap();
+        let center = equilateral.center_of(&registry.kimberling(9).unwrap().function);
+        assert!(same(center.unwrap(), [1.0 / 3.0; 3]));
+        let undefined = CenterFunction::Barycentric(Arc::new(|a: f64, b: f64, _: f64| a - b));
+        assert_eq!(equilateral.center_of(&undefined), None);
+        Ok(())
+    }
+}
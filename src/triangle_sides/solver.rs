@@ -0,0 +1,452 @@
+use super::{Float, GenericTriangle, Triangle, TriangleError};
+
+//one measured property, indices are the same as in the accessors
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Quantity {
+    Side(usize),
+    Angle(usize),
+    Height(usize),
+    Median(usize),
+    Bisector(usize),
+    Area,
+    Perimeter,
+    Inradius,
+    Circumradius,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Measurements<T = f64> {
+    pub sides: [Option<T>; 3],
+    pub angles: [Option<T>; 3],
+    pub heights: [Option<T>; 3],
+    pub medians: [Option<T>; 3],
+    pub bisectors: [Option<T>; 3],
+    pub area: Option<T>,
+    pub perimeter: Option<T>,
+    pub inradius: Option<T>,
+    pub circumradius: Option<T>,
+}
+
+impl<T> Default for Measurements<T> {
+    fn default() -> Measurements<T> {
+        Measurements {
+            sides: [None, None, None],
+            angles: [None, None, None],
+            heights: [None, None, None],
+            medians: [None, None, None],
+            bisectors: [None, None, None],
+            area: None,
+            perimeter: None,
+            inradius: None,
+            circumradius: None,
+        }
+    }
+}
+
+impl<T: Float> Measurements<T> {
+    //every given value with its quantity
+    pub fn quantities(&self) -> Vec<(Quantity, T)> {
+        let mut quantities = Vec::new();
+        let arrays = [
+            (Quantity::Side as fn(usize) -> Quantity, self.sides),
+            (Quantity::Angle, self.angles),
+            (Quantity::Height, self.heights),
+            (Quantity::Median, self.medians),
+            (Quantity::Bisector, self.bisectors),
+        ];
+        for (quantity, values) in arrays {
+            for (index, value) in values.into_iter().enumerate() {
+                if let Some(value) = value {
+                    quantities.push((quantity(index), value));
+                }
+            }
+        }
+        let single = [
+            (Quantity::Area, self.area),
+            (Quantity::Perimeter, self.perimeter),
+            (Quantity::Inradius, self.inradius),
+            (Quantity::Circumradius, self.circumradius),
+        ];
+        for (quantity, value) in single {
+            if let Some(value) = value {
+                quantities.push((quantity, value));
+            }
+        }
+        quantities
+    }
+}
+
+impl<T: Float> GenericTriangle<T> {
+    pub fn solve(measurements: &Measurements<T>, tolerance: T) -> Vec<GenericTriangle<T>> {
+        Self::try_solve(measurements, tolerance).unwrap_or_default()
+    }
+    //returns every triangle matching all the measurements within the relative tolerance,
+    //the search itself is numeric and done in f64
+    pub fn try_solve(
+        measurements: &Measurements<T>,
+        tolerance: T,
+    ) -> Result<Vec<GenericTriangle<T>>, TriangleError<T>> {
+        let quantities = measurements.quantities();
+        for &(quantity, value) in &quantities {
+            let valid = match quantity {
+                Quantity::Angle(_) => value > T::zero() && value < T::pi(),
+                _ => value > T::zero(),
+            };
+            if !valid {
+                return Err(TriangleError::InvalidMeasurement { quantity, value });
+            }
+        }
+        let known: Vec<(Quantity, f64)> = quantities
+            .iter()
+            .map(|&(quantity, value)| (quantity, value.to_f64()))
+            .collect();
+        let tolerance = tolerance.to_f64();
+        let starts = starts(&known);
+        //a generic point tells how many of the measurements are independent
+        let independent = starts
+            .iter()
+            .take(8)
+            .filter_map(|&start| jacobian(&known, start).map(|j| rank(&j)))
+            .max()
+            .unwrap_or(0);
+        if independent < 3 {
+            return Err(TriangleError::Underdetermined { independent });
+        }
+        let mut solutions: Vec<[f64; 3]> = Vec::new();
+        //measurement that fits the worst in the best triangle found
+        let mut best: Option<(Quantity, f64, f64)> = None;
+        for start in starts {
+            let Some(params) = levenberg_marquardt(&known, start) else {
+                continue;
+            };
+            let Some(residuals) = residuals(&known, params) else {
+                continue;
+            };
+            let (worst, residual) = residuals
+                .iter()
+                .enumerate()
+                .map(|(i, residual)| (known[i].0, residual.abs()))
+                .fold(
+                    (known[0].0, 0.0),
+                    |max, next| if next.1 > max.1 { next } else { max },
+                );
+            if residual <= tolerance {
+                let sides = sides(params);
+                let duplicate = solutions.iter().any(|solution| {
+                    (0..3).all(|i| (solution[i] - sides[i]).abs() <= 1e-6 * sides[i])
+                });
+                if !duplicate {
+                    solutions.push(sides);
+                }
+            }
+            let cost = residuals
+                .iter()
+                .map(|residual| residual * residual)
+                .sum::<f64>();
+            if best.is_none_or(|(_, _, best_cost)| cost < best_cost) {
+                best = Some((worst, residual, cost));
+            }
+        }
+        if solutions.is_empty() {
+            let (quantity, residual, _) = best.unwrap_or((known[0].0, f64::NAN, 0.0));
+            let residual = T::from_f64(residual);
+            return Err(if known.len() > independent as usize {
+                TriangleError::Inconsistent { quantity, residual }
+            } else {
+                TriangleError::Unsolvable { quantity, residual }
+            });
+        }
+        solutions.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        solutions
+            .into_iter()
+            .map(|sides| Self::try_new_from_sides(sides.map(T::from_f64)))
+            .collect()
+    }
+}
+
+//sides are y + z, z + x and x + y for positive x, y, z, so every point of the
+//search space is a triangle, params are the logarithms of x, y and z
+fn sides(params: [f64; 3]) -> [f64; 3] {
+    let [x, y, z] = params.map(f64::exp);
+    [y + z, z + x, x + y]
+}
+
+fn evaluate(triangle: &Triangle, quantity: Quantity) -> Option<f64> {
+    match quantity {
+        Quantity::Side(i) => triangle.side(i),
+        Quantity::Angle(i) => triangle.angle(i),
+        Quantity::Height(i) => triangle.height(i),
+        Quantity::Median(i) => triangle.median(i),
+        Quantity::Bisector(i) => triangle.bisector(i),
+        Quantity::Area => triangle.area(),
+        Quantity::Perimeter => triangle.perimeter(),
+        Quantity::Inradius => triangle.inradius(),
+        Quantity::Circumradius => triangle.circumradius(),
+    }
+}
+
+//relative differences between the triangle and the measurements
+fn residuals(known: &[(Quantity, f64)], params: [f64; 3]) -> Option<Vec<f64>> {
+    let triangle = Triangle::try_new_blank(sides(params)).ok()?;
+    known
+        .iter()
+        .map(|&(quantity, value)| Some(evaluate(&triangle, quantity)? / value - 1.0))
+        .collect()
+}
+
+fn jacobian(known: &[(Quantity, f64)], params: [f64; 3]) -> Option<Vec<[f64; 3]>> {
+    let step = 1e-6;
+    let mut columns = Vec::new();
+    for k in 0..3 {
+        let (mut forward, mut backward) = (params, params);
+        forward[k] += step;
+        backward[k] -= step;
+        let (forward, backward) = (residuals(known, forward)?, residuals(known, backward)?);
+        columns.push(
+            (0..known.len())
+                .map(|i| (forward[i] - backward[i]) / (2.0 * step))
+                .collect::<Vec<f64>>(),
+        );
+    }
+    Some(
+        (0..known.len())
+            .map(|i| [columns[0][i], columns[1][i], columns[2][i]])
+            .collect(),
+    )
+}
+
+//gaussian elimination with full pivoting
+fn rank(matrix: &[[f64; 3]]) -> u8 {
+    let mut rows = matrix.to_vec();
+    let scale = rows
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    let mut rank = 0;
+    let mut columns = vec![0, 1, 2];
+    while rank < rows.len() && !columns.is_empty() {
+        let mut pivot = (0.0, rank, 0);
+        for (r, row) in rows.iter().enumerate().skip(rank) {
+            for (c, &column) in columns.iter().enumerate() {
+                if row[column].abs() > pivot.0 {
+                    pivot = (row[column].abs(), r, c);
+                }
+            }
+        }
+        if pivot.0 <= 1e-7 * scale {
+            break;
+        }
+        rows.swap(rank, pivot.1);
+        let column = columns.remove(pivot.2);
+        let pivot_row = rows[rank];
+        for row in rows.iter_mut().skip(rank + 1) {
+            let factor = row[column] / pivot_row[column];
+            for c in 0..3 {
+                row[c] -= factor * pivot_row[c];
+            }
+        }
+        rank += 1;
+    }
+    rank as u8
+}
+
+fn solve_3x3(mut matrix: [[f64; 3]; 3], mut vector: [f64; 3]) -> Option<[f64; 3]> {
+    for k in 0..3 {
+        let pivot = (k..3).max_by(|&x, &y| matrix[x][k].abs().total_cmp(&matrix[y][k].abs()))?;
+        if matrix[pivot][k].abs() < 1e-300 {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        vector.swap(k, pivot);
+        let pivot_row = matrix[k];
+        for r in k + 1..3 {
+            let factor = matrix[r][k] / pivot_row[k];
+            for (value, pivot_value) in matrix[r].iter_mut().zip(pivot_row).skip(k) {
+                *value -= factor * pivot_value;
+            }
+            vector[r] -= factor * vector[k];
+        }
+    }
+    let mut result = [0.0; 3];
+    for k in (0..3).rev() {
+        let sum = (k + 1..3).map(|c| matrix[k][c] * result[c]).sum::<f64>();
+        result[k] = (vector[k] - sum) / matrix[k][k];
+    }
+    Some(result)
+}
+
+fn levenberg_marquardt(known: &[(Quantity, f64)], start: [f64; 3]) -> Option<[f64; 3]> {
+    let cost =
+        |params| residuals(known, params).map(|r| r.iter().map(|value| value * value).sum::<f64>());
+    let mut params = start;
+    let mut current = cost(params)?;
+    let mut damping = 1e-3;
+    for _ in 0..200 {
+        if current < 1e-30 {
+            break;
+        }
+        let r = residuals(known, params)?;
+        let j = jacobian(known, params)?;
+        let mut normal = [[0.0; 3]; 3];
+        let mut gradient = [0.0; 3];
+        for (row, residual) in j.iter().zip(&r) {
+            for a in 0..3 {
+                gradient[a] -= row[a] * residual;
+                for b in 0..3 {
+                    normal[a][b] += row[a] * row[b];
+                }
+            }
+        }
+        let mut improved = false;
+        while damping < 1e12 {
+            let mut damped = normal;
+            for (k, row) in damped.iter_mut().enumerate() {
+                row[k] += damping * (normal[k][k] + 1e-12);
+            }
+            if let Some(step) = solve_3x3(damped, gradient) {
+                let next = [0, 1, 2].map(|k| params[k] + step[k]);
+                if let Some(next_cost) = cost(next).filter(|next_cost| *next_cost < current) {
+                    let small = step.iter().all(|value| value.abs() < 1e-15);
+                    params = next;
+                    current = next_cost;
+                    damping = (damping / 3.0).max(1e-12);
+                    improved = !small;
+                    break;
+                }
+            }
+            damping *= 4.0;
+        }
+        if !improved {
+            break;
+        }
+    }
+    Some(params)
+}
+
+//shapes spread over the search space, scaled to the lengths in the measurements
+fn starts(known: &[(Quantity, f64)]) -> Vec<[f64; 3]> {
+    let length = known
+        .iter()
+        .find_map(|&(quantity, value)| match quantity {
+            Quantity::Angle(_) => None,
+            Quantity::Area => Some(value.sqrt()),
+            Quantity::Perimeter => Some(value / 3.0),
+            _ => Some(value),
+        })
+        .unwrap_or(1.0);
+    let shapes = [-2.0, -0.7, 0.0, 0.7, 2.0];
+    let mut starts = Vec::new();
+    for x in shapes {
+        for y in shapes {
+            for z in shapes {
+                starts.push([x, y, z].map(|value: f64| value + (length / 2.0).ln()));
+            }
+        }
+    }
+    //generic points first, the equal shapes are special for some measurements
+    starts.sort_by_key(|start| (start[0] == start[1]) as u8 + (start[1] == start[2]) as u8);
+    starts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::{Measurements, Quantity, Triangle, TriangleError};
+    const TOLERANCE: f64 = 0.000000001;
+
+    fn has(solutions: &[Triangle], sides: [f64; 3]) -> bool {
+        solutions.iter().any(|triangle| {
+            (0..3).all(|i| (triangle.side(i).unwrap() - sides[i]).abs() < TOLERANCE * 10.0)
+        })
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), String> {
+        //two sides and a height, the angle between the sides is acute or obtuse
+        let measurements = Measurements {
+            sides: [None, Some(4.0), Some(3.0)],
+            heights: [Some(2.4), None, None],
+            ..Default::default()
+        };
+        let solutions = Triangle::solve(&measurements, TOLERANCE);
+        assert_eq!(solutions.len(), 2);
+        assert!(has(&solutions, [5.0, 4.0, 3.0]));
+        for triangle in &solutions {
+            assert!((triangle.height(0).unwrap() - 2.4).abs() < TOLERANCE * 10.0);
+        }
+        //a side, the area and the perimeter
+        let measurements = Measurements {
+            sides: [Some(5.0), None, None],
+            area: Some(6.0),
+            perimeter: Some(12.0),
+            ..Default::default()
+        };
+        let solutions = Triangle::solve(&measurements, TOLERANCE);
+        assert_eq!(solutions.len(), 2);
+        assert!(has(&solutions, [5.0, 3.0, 4.0]) && has(&solutions, [5.0, 4.0, 3.0]));
+        //three medians
+        let egyptian = Triangle::new_from_sides([5.0, 3.0, 4.0]).unwrap();
+        let measurements = Measurements {
+            medians: egyptian.medians(),
+            ..Default::default()
+        };
+        let solutions = Triangle::solve(&measurements, TOLERANCE);
+        assert_eq!(solutions.len(), 1);
+        assert!(has(&solutions, [5.0, 3.0, 4.0]));
+        //a side, an angle and the circumradius over the same side are dependent,
+        //the inradius makes them enough
+        let measurements = Measurements {
+            sides: [Some(5.0), None, None],
+            angles: [egyptian.angle(0), None, None],
+            circumradius: Some(2.5),
+            inradius: Some(1.0),
+            ..Default::default()
+        };
+        let solutions = Triangle::solve(&measurements, TOLERANCE);
+        assert!(has(&solutions, [5.0, 3.0, 4.0]) && has(&solutions, [5.0, 4.0, 3.0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_errors() -> Result<(), String> {
+        let angles = Measurements {
+            angles: [Some(1.0), Some(1.0), Some(1.14159)],
+            ..Default::default()
+        };
+        assert_eq!(
+            Triangle::try_solve(&angles, TOLERANCE).unwrap_err(),
+            TriangleError::Underdetermined { independent: 2 }
+        );
+        let inconsistent = Measurements {
+            sides: [Some(5.0), Some(3.0), Some(4.0)],
+            area: Some(7.0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Triangle::try_solve(&inconsistent, TOLERANCE),
+            Err(TriangleError::Inconsistent { .. })
+        ));
+        //the height to side 1 cannot be longer than side 2
+        let impossible = Measurements {
+            sides: [None, Some(1.0), Some(1.0)],
+            heights: [None, Some(5.0), None],
+            ..Default::default()
+        };
+        assert!(matches!(
+            Triangle::try_solve(&impossible, TOLERANCE),
+            Err(TriangleError::Unsolvable { .. })
+        ));
+        let negative = Measurements {
+            sides: [Some(5.0), Some(3.0), None],
+            medians: [Some(-1.0), None, None],
+            ..Default::default()
+        };
+        assert_eq!(
+            Triangle::try_solve(&negative, TOLERANCE).unwrap_err(),
+            TriangleError::InvalidMeasurement {
+                quantity: Quantity::Median(0),
+                value: -1.0
+            }
+        );
+        Ok(())
+    }
+}
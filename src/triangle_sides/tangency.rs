@@ -0,0 +1,101 @@
+use super::{Float, GenericTriangle, Slot};
+
+impl<T: Float, S: Slot<T>> GenericTriangle<T, S> {
+    //length of both tangents from vertex i to the incircle, s - sides[i]
+    pub fn tangent_length(&self, index: usize) -> Option<T> {
+        if !Self::check_index(index) {
+            return None;
+        }
+        let sp = self.perimeter()? / T::from_f64(2.0); // semi-perimeter
+        Some(sp - self.sides[index])
+    }
+    pub fn tangent_lengths(&self) -> [Option<T>; 3] {
+        [
+            self.tangent_length(0),
+            self.tangent_length(1),
+            self.tangent_length(2),
+        ]
+    }
+    //distances of the point where the incircle touches side i from the vertices
+    //(i + 1) % 3 and (i + 2) % 3
+    pub fn incircle_split(&self, index: usize) -> Option<(T, T)> {
+        if !Self::check_index(index) {
+            return None;
+        }
+        Some((
+            self.tangent_length((index + 1) % 3)?,
+            self.tangent_length((index + 2) % 3)?,
+        ))
+    }
+    pub fn incircle_splits(&self) -> [Option<(T, T)>; 3] {
+        [
+            self.incircle_split(0),
+            self.incircle_split(1),
+            self.incircle_split(2),
+        ]
+    }
+    //the same for the excircle tangent to side i, the split is swapped
+    pub fn excircle_split(&self, index: usize) -> Option<(T, T)> {
+        let (from_1, from_2) = self.incircle_split(index)?;
+        Some((from_2, from_1))
+    }
+    pub fn excircle_splits(&self) -> [Option<(T, T)>; 3] {
+        [
+            self.excircle_split(0),
+            self.excircle_split(1),
+            self.excircle_split(2),
+        ]
+    }
+    //length of both tangents from vertex i to the excircle tangent to side i,
+    //it is the semi-perimeter for every i
+    pub fn excircle_tangent_length(&self) -> Option<T> {
+        Some(self.perimeter()? / T::from_f64(2.0))
+    }
+    //sides of the contact (intouch) triangle, side i joins the points where the
+    //incircle touches the sides next to vertex i
+    pub fn contact_side(&self, index: usize) -> Option<T> {
+        let two = T::from_f64(2.0);
+        Some(two * self.tangent_length(index)? * (self.angle(index)? / two).sin())
+    }
+    pub fn contact_sides(&self) -> [Option<T>; 3] {
+        [
+            self.contact_side(0),
+            self.contact_side(1),
+            self.contact_side(2),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::Triangle;
+
+    #[test]
+    fn test_tangent_lengths() -> Result<(), String> {
+        let triangle = Triangle::new_from_sides([5.0, 3.0, 4.0]).unwrap();
+        assert_eq!(
+            triangle.tangent_lengths(),
+            [Some(1.0), Some(3.0), Some(2.0)]
+        );
+        assert_eq!(
+            triangle.incircle_splits(),
+            [Some((3.0, 2.0)), Some((2.0, 1.0)), Some((1.0, 3.0))]
+        );
+        assert_eq!(triangle.excircle_split(0), Some((2.0, 3.0)));
+        assert_eq!(triangle.excircle_split(3), None);
+        assert_eq!(triangle.excircle_tangent_length(), Some(6.0));
+        for (i, split) in triangle.excircle_splits().into_iter().enumerate() {
+            let (from_1, from_2) = split.unwrap();
+            assert_eq!(from_1 + from_2, triangle.side(i).unwrap());
+        }
+        //right angle at vertex 0
+        let contact = triangle.contact_sides();
+        assert!((contact[0].unwrap() - 2.0_f64.sqrt()).abs() < ROUNDING_ERROR);
+        let intouch = triangle.intouch().unwrap();
+        for (i, side) in contact.into_iter().enumerate() {
+            assert!((side.unwrap() - intouch.side(i).unwrap()).abs() < ROUNDING_ERROR);
+        }
+        Ok(())
+    }
+}
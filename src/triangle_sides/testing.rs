@@ -0,0 +1,24 @@
+//comparisons shared by the tests of the crate and its submodules
+use crate::triangle_sides::{Point, Triangle};
+
+pub const ROUNDING_ERROR: f64 = 0.0000000000001;
+
+//coordinates of two points or centers are the same
+pub fn same(p: [f64; 3], q: [f64; 3]) -> bool {
+    (0..3).all(|i| (p[i] - q[i]).abs() < ROUNDING_ERROR)
+}
+
+//the point is in the plane at (x, y)
+pub fn close(point: Option<Point<f64>>, x: f64, y: f64) -> bool {
+    match point {
+        Some(Point::Plane(px, py)) => {
+            (px - x).abs() < ROUNDING_ERROR && (py - y).abs() < ROUNDING_ERROR
+        }
+        _ => false,
+    }
+}
+
+//the triangle has the given sides, derived triangles lose a few digits
+pub fn same_sides(triangle: Triangle, sides: [f64; 3]) -> bool {
+    (0..3).all(|i| (triangle.side(i).unwrap() - sides[i]).abs() < ROUNDING_ERROR * 100.0)
+}
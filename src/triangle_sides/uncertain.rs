@@ -0,0 +1,212 @@
+use super::{Float, GenericTriangle};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//measured value with first-order error propagation, contributions[i] is the
+//derivative by side i times the standard deviation of side i
+#[derive(Copy, Clone, Debug)]
+pub struct Uncertain {
+    pub value: f64,
+    pub contributions: [f64; 3],
+}
+
+pub type MeasuredTriangle = GenericTriangle<Uncertain>;
+
+impl Uncertain {
+    //side at the given index measured with the standard deviation, the index
+    //is always 0, 1 or 2, so it is only used through sides()
+    fn side(value: f64, deviation: f64, index: usize) -> Uncertain {
+        let mut contributions = [0.0; 3];
+        contributions[index] = deviation;
+        Uncertain {
+            value,
+            contributions,
+        }
+    }
+    pub fn sides(values: [f64; 3], deviations: [f64; 3]) -> [Uncertain; 3] {
+        [0, 1, 2].map(|i| Uncertain::side(values[i], deviations[i], i))
+    }
+    //errors of the sides are independent, so they add up in squares
+    pub fn deviation(&self) -> f64 {
+        self.contributions
+            .iter()
+            .map(|contribution| contribution * contribution)
+            .sum::<f64>()
+            .sqrt()
+    }
+    fn chain(self, value: f64, derivative: f64) -> Uncertain {
+        Uncertain {
+            value,
+            contributions: self
+                .contributions
+                .map(|contribution| contribution * derivative),
+        }
+    }
+    fn combine(self, other: Uncertain, value: f64, derivatives: (f64, f64)) -> Uncertain {
+        Uncertain {
+            value,
+            contributions: [0, 1, 2].map(|i| {
+                self.contributions[i] * derivatives.0 + other.contributions[i] * derivatives.1
+            }),
+        }
+    }
+}
+
+impl MeasuredTriangle {
+    //use try_new_from_sides(Uncertain::sides(..)) to get the error
+    pub fn new_measured(sides: [f64; 3], deviations: [f64; 3]) -> Option<MeasuredTriangle> {
+        Self::new_from_sides(Uncertain::sides(sides, deviations))
+    }
+}
+
+//only the values are compared
+impl PartialEq for Uncertain {
+    fn eq(&self, other: &Uncertain) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for Uncertain {
+    fn partial_cmp(&self, other: &Uncertain) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Display for Uncertain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ± {}", self.value, self.deviation())
+    }
+}
+
+impl Add for Uncertain {
+    type Output = Uncertain;
+    fn add(self, other: Uncertain) -> Uncertain {
+        self.combine(other, self.value + other.value, (1.0, 1.0))
+    }
+}
+
+impl Sub for Uncertain {
+    type Output = Uncertain;
+    fn sub(self, other: Uncertain) -> Uncertain {
+        self.combine(other, self.value - other.value, (1.0, -1.0))
+    }
+}
+
+impl Mul for Uncertain {
+    type Output = Uncertain;
+    fn mul(self, other: Uncertain) -> Uncertain {
+        self.combine(other, self.value * other.value, (other.value, self.value))
+    }
+}
+
+impl Div for Uncertain {
+    type Output = Uncertain;
+    fn div(self, other: Uncertain) -> Uncertain {
+        let value = self.value / other.value;
+        self.combine(other, value, (1.0 / other.value, -value / other.value))
+    }
+}
+
+impl Neg for Uncertain {
+    type Output = Uncertain;
+    fn neg(self) -> Uncertain {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+impl Float for Uncertain {
+    fn from_f64(value: f64) -> Uncertain {
+        Uncertain {
+            value,
+            contributions: [0.0; 3],
+        }
+    }
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+    fn pi() -> Uncertain {
+        Uncertain::from_f64(core::f64::consts::PI)
+    }
+    fn rounding_error() -> Uncertain {
+        Uncertain::from_f64(f64::rounding_error())
+    }
+    fn sqrt(self) -> Uncertain {
+        let value = self.value.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+    fn sin(self) -> Uncertain {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+    fn cos(self) -> Uncertain {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+    fn tan(self) -> Uncertain {
+        let value = self.value.tan();
+        self.chain(value, 1.0 + value * value)
+    }
+    fn asin(self) -> Uncertain {
+        let derivative = 1.0 / (1.0 - self.value * self.value).sqrt();
+        self.chain(self.value.asin(), derivative)
+    }
+    fn acos(self) -> Uncertain {
+        let derivative = -1.0 / (1.0 - self.value * self.value).sqrt();
+        self.chain(self.value.acos(), derivative)
+    }
+    fn atan(self) -> Uncertain {
+        let derivative = 1.0 / (1.0 + self.value * self.value);
+        self.chain(self.value.atan(), derivative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::triangle_sides::testing::ROUNDING_ERROR;
+    use crate::triangle_sides::{MeasuredTriangle, Triangle, Uncertain};
+
+    #[test]
+    fn test_area_deviation() -> Result<(), String> {
+        let triangle = MeasuredTriangle::new_measured([5.0, 3.0, 4.0], [0.1, 0.1, 0.1]).unwrap();
+        let area = triangle.area().unwrap();
+        assert!((area.value - 6.0).abs() < ROUNDING_ERROR);
+        //derivatives of the area by the sides are 0, 2 and 1.5
+        assert!((area.contributions[0]).abs() < ROUNDING_ERROR);
+        assert!((area.contributions[1] - 0.2).abs() < ROUNDING_ERROR);
+        assert!((area.deviation() - 0.25).abs() < ROUNDING_ERROR);
+        assert_eq!(format!("{}", Uncertain::side(2.0, 0.5, 1)), "2 ± 0.5");
+        Ok(())
+    }
+
+    #[test]
+    fn test_derivatives() -> Result<(), String> {
+        //compare with finite differences of the f64 triangle
+        let sides = [6.0, 5.0, 4.0];
+        let measured = MeasuredTriangle::new_measured(sides, [1.0; 3]).unwrap();
+        let step = 0.000001;
+        for i in 0..3 {
+            let (mut forward, mut backward) = (sides, sides);
+            forward[i] += step;
+            backward[i] -= step;
+            let (forward, backward) = (
+                Triangle::new_from_sides(forward).unwrap(),
+                Triangle::new_from_sides(backward).unwrap(),
+            );
+            let derivative = |f: fn(&Triangle) -> Option<f64>| {
+                (f(&forward).unwrap() - f(&backward).unwrap()) / (2.0 * step)
+            };
+            let pairs = [
+                (measured.angle(0).unwrap(), derivative(|t| t.angle(0))),
+                (
+                    measured.circumradius().unwrap(),
+                    derivative(|t| t.circumradius()),
+                ),
+                (measured.inradius().unwrap(), derivative(|t| t.inradius())),
+                (measured.height(1).unwrap(), derivative(|t| t.height(1))),
+                (measured.median(2).unwrap(), derivative(|t| t.median(2))),
+            ];
+            for (uncertain, expected) in pairs {
+                assert!((uncertain.contributions[i] - expected).abs() < 0.000001);
+            }
+        }
+        Ok(())
+    }
+}